@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, io};
+
+use serde::Deserialize;
+
+use crate::year_dir;
+
+/// An answer as written in `src/<year>/answers/NN.toml`, either `part_one = 42` or `part_one = "abc"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// The known correct answers for a day.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part_one: Option<Answer>,
+    part_two: Option<Answer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::IoError(e) => write!(f, "could not read answers file: {e}"),
+            AnswersError::ParseError(e) => write!(f, "could not parse answers file: {e}"),
+        }
+    }
+}
+
+impl Answers {
+    /// Loads `src/<year>/answers/NN.toml`. A missing file means no answers are known yet.
+    pub fn load(year: u16, day: u8) -> Result<Self, AnswersError> {
+        let filepath = year_dir(year)
+            .join("answers")
+            .join(format!("{day:02}.toml"));
+
+        match fs::read_to_string(filepath) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::IoError(e)),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, AnswersError> {
+        toml::from_str(contents).map_err(AnswersError::ParseError)
+    }
+
+    /// Returns the known answer for `part`, if any.
+    pub fn get(&self, part: u8) -> Option<String> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+        .map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part_one = 1586300\npart_two = \"cqkaabcc\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("1586300".into()));
+        assert_eq!(answers.get(2), Some("cqkaabcc".into()));
+
+        let answers = Answers::parse("part_one = -3\n").unwrap();
+        assert_eq!(answers.get(1), Some("-3".into()));
+        assert_eq!(answers.get(2), None);
+
+        assert!(Answers::parse("part_three = 1\n").is_err());
+    }
+}
@@ -0,0 +1,491 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, create_dir_all},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::year_dir;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AocClientError {
+    SessionNotFound,
+    SessionExpired,
+    NotReleased,
+    RateLimited(Option<Duration>),
+    BadStatus(u16),
+    RequestFailed(String),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Paste it into \"~/{SESSION_FILE}\" or set ADVENT_OF_CODE_SESSION."
+            ),
+            AocClientError::SessionExpired => {
+                write!(f, "the session cookie was rejected, it has probably expired.")
+            }
+            AocClientError::NotReleased => write!(f, "this puzzle has not been released yet."),
+            AocClientError::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, try again in {}s.", wait.as_secs())
+            }
+            AocClientError::RateLimited(None) => write!(f, "rate limited, try again later."),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with unexpected status {status}.")
+            }
+            AocClientError::RequestFailed(e) => write!(f, "request failed: {e}"),
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+/// A hint that accompanies some wrong answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The server's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        /// Time until the next answer is accepted.
+        wait: Option<Duration>,
+    },
+    AlreadyCompleted,
+    Unknown(String),
+}
+
+/// A minimal client for the Advent of Code website.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: u16,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client from the environment.
+    ///
+    /// The session cookie is read from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`,
+    /// the base URL from `AOC_BASE_URL`.
+    pub fn from_env(year: u16) -> Result<Self, AocClientError> {
+        let session = match env::var("ADVENT_OF_CODE_SESSION") {
+            Ok(session) => session,
+            Err(_) => session_file_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .ok_or(AocClientError::SessionNotFound)?,
+        };
+
+        if session.trim().is_empty() {
+            return Err(AocClientError::SessionNotFound);
+        }
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Fetches the puzzle description and converts it to markdown.
+    pub fn puzzle(&self, day: u8) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{}/day/{day}", self.year))?;
+
+        Ok(extract_tag_contents(&html, "<article", "</article>")
+            .into_iter()
+            .map(|article| html2md::parse_html(article).trim().to_string())
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn input(&self, day: u8) -> Result<String, AocClientError> {
+        self.get(&format!("/{}/day/{day}/input", self.year))
+    }
+
+    /// Submits `answer` for the given part.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, AocClientError> {
+        let html = self.post(
+            &format!("/{}/day/{day}/answer", self.year),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+
+        let message = extract_tag_contents(&html, "<article", "</article>")
+            .first()
+            .map(|article| strip_tags(article))
+            .unwrap_or_default();
+
+        parse_verdict(&message)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(map_error)?
+            .into_string()
+            .map_err(|e| AocClientError::RequestFailed(e.to_string()))
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, AocClientError> {
+        self.agent
+            .post(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form)
+            .map_err(map_error)?
+            .into_string()
+            .map_err(|e| AocClientError::RequestFailed(e.to_string()))
+    }
+}
+
+/// Downloads input and puzzle description to `src/<year>/inputs` and `src/<year>/puzzles`.
+pub fn download(client: &AocClient, day: u8) -> Result<(), AocClientError> {
+    let input_path = get_input_path(client.year(), day);
+    let puzzle_path = get_puzzle_path(client.year(), day);
+
+    println!("Fetching puzzle for day {day}, {}...", client.year());
+    let puzzle = client.puzzle(day)?;
+    println!("Downloading input for day {day}, {}...", client.year());
+    let input = client.input(day)?;
+
+    for path in [&input_path, &puzzle_path] {
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir).map_err(|_| AocClientError::IoError)?;
+        }
+    }
+    fs::write(&input_path, input).map_err(|_| AocClientError::IoError)?;
+    fs::write(&puzzle_path, puzzle).map_err(|_| AocClientError::IoError)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+pub fn get_input_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join("inputs").join(format!("{day:02}.txt"))
+}
+
+pub fn get_puzzle_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join("puzzles").join(format!("{day:02}.md"))
+}
+
+fn session_file_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+fn map_error(error: ureq::Error) -> AocClientError {
+    match error {
+        ureq::Error::Status(404, _) => AocClientError::NotReleased,
+        ureq::Error::Status(400 | 401 | 403, _) => AocClientError::SessionExpired,
+        ureq::Error::Status(429, response) => AocClientError::RateLimited(
+            response
+                .header("Retry-After")
+                .and_then(|secs| secs.parse().ok())
+                .map(Duration::from_secs),
+        ),
+        ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+        ureq::Error::Transport(e) => AocClientError::RequestFailed(e.to_string()),
+    }
+}
+
+fn parse_verdict(message: &str) -> Result<Verdict, AocClientError> {
+    if message.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if message.contains("You gave an answer too recently") {
+        Err(AocClientError::RateLimited(parse_wait(message)))
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Ok(Verdict::Incorrect {
+            hint,
+            wait: parse_wait(message),
+        })
+    } else if message.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadyCompleted)
+    } else {
+        Ok(Verdict::Unknown(message.trim().to_string()))
+    }
+}
+
+/// Parses wait times like "You have 4m 32s left to wait" or "please wait 5 minutes".
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((_, rest)) = message.split_once("You have ") {
+        let (time, _) = rest.split_once(" left to wait")?;
+        return time
+            .split_whitespace()
+            .map(|token| {
+                let unit = match token.chars().last()? {
+                    'h' => 3600,
+                    'm' => 60,
+                    's' => 1,
+                    _ => return None,
+                };
+                let value: u64 = token[..token.len() - 1].parse().ok()?;
+                Some(value * unit)
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let (_, rest) = message.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(amount * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(amount)),
+        _ => None,
+    }
+}
+
+/// Returns the inner contents of every occurrence of a tag, e.g. all `<article>` elements.
+fn extract_tag_contents<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut contents = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let Some(body) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[body..].find(close).map(|i| body + i) else {
+            break;
+        };
+        contents.push(&rest[body..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    contents
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text
+}
+
+/// Advent of Code unlocks in December at midnight EST, before that the previous event is the latest.
+pub(crate) fn latest_event_year() -> u16 {
+    let est_seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+        - 5 * 3600;
+    let (year, month) = civil_from_days(est_seconds.div_euclid(86_400));
+    if month == 12 {
+        year as u16
+    } else {
+        year as u16 - 1
+    }
+}
+
+/// Converts days since the unix epoch to a (year, month) pair.
+/// see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves one canned response per request and reports each received request.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8(body_bytes).unwrap());
+                tx.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn test_input_and_puzzle() {
+        let (base_url, requests) = stub_server(vec![
+            (200, "1x2x3\n"),
+            (
+                200,
+                "<main><article class=\"day-desc\"><h2>--- Day 2 ---</h2><p>An <em>example</em>:</p><pre><code>2x3x4\n</code></pre></article></main>",
+            ),
+        ]);
+        let client = AocClient::new(&base_url, "secret\n", 2015);
+
+        assert_eq!(client.input(2), Ok("1x2x3\n".to_string()));
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2015/day/2/input "));
+        assert!(request.contains("session=secret\r\n"));
+
+        let puzzle = client.puzzle(2).unwrap();
+        assert!(puzzle.starts_with("\\--- Day 2 ---\n---"));
+        assert!(puzzle.contains("An *example*:"));
+        assert!(puzzle.contains("```\n2x3x4\n"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, _requests) = stub_server(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (429, "Too many requests"),
+            (500, "Internal Server Error"),
+        ]);
+        let client = AocClient::new(&base_url, "secret", 2015);
+
+        assert_eq!(client.input(25), Err(AocClientError::NotReleased));
+        assert_eq!(client.input(1), Err(AocClientError::SessionExpired));
+        assert_eq!(client.input(1), Err(AocClientError::RateLimited(None)));
+        assert_eq!(client.input(1), Err(AocClientError::BadStatus(500)));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = stub_server(vec![
+            (200, "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2015/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2015/day/1\">[Return to Day 1]</a></p></article></main>"),
+            (200, "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2015/day/1\">[Return to Day 1]</a></p></article></main>"),
+            (200, "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to powering the weather machine.</p></article></main>"),
+        ]);
+        let client = AocClient::new(&base_url, "secret", 2015);
+
+        assert_eq!(
+            client.submit(1, 1, "300"),
+            Ok(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            })
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2015/day/1/answer "));
+        assert!(request.ends_with("level=1&answer=300"));
+
+        assert_eq!(
+            client.submit(1, 1, "280"),
+            Err(AocClientError::RateLimited(Some(Duration::from_secs(272))))
+        );
+        assert_eq!(client.submit(1, 1, "280"), Ok(Verdict::Correct));
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(
+            parse_wait("You have 1h 2m 3s left to wait."),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            parse_wait("please wait 5 minutes"),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(parse_wait("You have 4m 3é left to wait."), None);
+        assert_eq!(parse_wait("You have 4 left to wait."), None);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        assert_eq!(civil_from_days(16_770), (2015, 12));
+        assert_eq!(civil_from_days(20_088), (2024, 12));
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+/// Upper bound for warmup iterations, fewer are run if they exceed a tenth of the budget.
+const WARMUP_ITERATIONS: usize = 3;
+
+/// Timing statistics collected over repeated runs of a solver.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BenchStats {
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "crate::runner::serialize_nanos")]
+    pub min: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "crate::runner::serialize_nanos")]
+    pub mean: Duration,
+    #[serde(
+        rename = "median_ns",
+        serialize_with = "crate::runner::serialize_nanos"
+    )]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "crate::runner::serialize_nanos")]
+    pub p95: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics from a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+        samples.sort_unstable();
+
+        let iterations = samples.len();
+        let total: Duration = samples.iter().sum();
+        let median = if iterations.is_multiple_of(2) {
+            (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2
+        } else {
+            samples[iterations / 2]
+        };
+        // nearest-rank percentile.
+        let p95 = samples[(iterations * 95).div_ceil(100) - 1];
+
+        Self {
+            iterations,
+            min: samples[0],
+            mean: total / iterations as u32,
+            median,
+            p95,
+        }
+    }
+}
+
+/// Runs `solver` repeatedly until `budget` is used up, after a short warmup.
+///
+/// At least one measured iteration is always run, even if it exceeds the budget.
+pub fn bench<T>(solver: impl Fn(&str) -> T, input: &str, budget: Duration) -> BenchStats {
+    let warmup = Instant::now();
+    for _ in 0..WARMUP_ITERATIONS {
+        black_box(solver(black_box(input)));
+        if warmup.elapsed() > budget / 10 {
+            break;
+        }
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.is_empty() || start.elapsed() < budget {
+        let timer = Instant::now();
+        black_box(solver(black_box(input)));
+        samples.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let stats = BenchStats::from_samples((1..=20).rev().map(Duration::from_millis).collect());
+        assert_eq!(
+            stats,
+            BenchStats {
+                iterations: 20,
+                min: Duration::from_millis(1),
+                mean: Duration::from_micros(10_500),
+                median: Duration::from_micros(10_500),
+                p95: Duration::from_millis(19),
+            }
+        );
+
+        let stats = BenchStats::from_samples(vec![Duration::from_nanos(7)]);
+        assert_eq!(stats.median, Duration::from_nanos(7));
+        assert_eq!(stats.p95, Duration::from_nanos(7));
+    }
+}
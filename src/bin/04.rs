@@ -1,42 +1,7 @@
-fn find_nonce_for_suffix(secret_key: &str, prefix: &'static str) -> Option<u32> {
-    for nonce in 1.. {
-        let digest = md5::compute(format!("{secret_key}{nonce}"));
-        if format!("{digest:x}").starts_with(prefix) {
-            return Some(nonce);
-        }
-    }
-    None
-}
-
-pub fn part_one(input: &str) -> Option<u32> {
-    find_nonce_for_suffix(input, "00000")
-}
-
-pub fn part_two(input: &str) -> Option<u32> {
-    find_nonce_for_suffix(input, "000000")
-}
+use advent_of_code::days::y2015::Day04;
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    #[ignore]
-    fn test_part_one() {
-        assert_eq!(part_one("abcdef"), Some(609043));
-        assert_eq!(part_one("pqrstuv"), Some(1048970));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_two(&input), None);
-    }
+    let input = &advent_of_code::read_input_or_exit(2015, 4);
+    advent_of_code::solve!(Day04, 1, input);
+    advent_of_code::solve!(Day04, 2, input);
 }
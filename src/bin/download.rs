@@ -2,19 +2,22 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::{self, AocClient};
+use advent_of_code::config;
 use std::process;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
     })
 }
 
@@ -27,20 +30,16 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let client = match AocClient::from_env(args.year) {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("Failed to create client: {e}");
             process::exit(1);
         }
+    };
+
+    if let Err(e) = aoc_client::download(&client, args.day) {
+        eprintln!("Failed to download day {}: {e}", args.day);
+        process::exit(1);
     }
 }
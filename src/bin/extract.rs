@@ -0,0 +1,51 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::config;
+use advent_of_code::extract::{write_examples, ExtractError};
+use std::process;
+
+struct Args {
+    day: u8,
+    year: u16,
+    overwrite: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        overwrite: args.contains("--overwrite"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    match write_examples(args.year, args.day, args.overwrite) {
+        Ok(_) => {}
+        Err(ExtractError::PuzzleNotFound(path)) => {
+            eprintln!(
+                "Could not read \"{}\". Run `cargo download {} --year {}` first.",
+                path.display(),
+                args.day,
+                args.year
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to extract examples: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -2,22 +2,56 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::aoc_client::{self, AocClient};
+use advent_of_code::config;
+use std::{fs, path::Path, process};
+
+/// Used when stdout is not a terminal.
+const DEFAULT_WIDTH: usize = 80;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        refresh: args.contains("--refresh"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
+/// Downloads the puzzle description and stores it next to the other puzzles.
+fn fetch_puzzle(day: u8, year: u16, path: &Path) -> String {
+    let client = match AocClient::from_env(year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzle = match client.puzzle(day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Failed to read day {day}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let dir = path.parent().unwrap_or(path);
+    if let Err(e) = fs::create_dir_all(dir).and_then(|_| fs::write(path, &puzzle)) {
+        eprintln!("Failed to write puzzle to \"{}\": {e}", path.display());
+    }
+
+    puzzle
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -27,20 +61,23 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let path = aoc_client::get_puzzle_path(args.year, args.day);
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
+    let puzzle = if !args.refresh && path.exists() {
+        match fs::read_to_string(&path) {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e}", path.display());
                 process::exit(1);
             }
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            process::exit(1);
-        }
-    }
+    } else {
+        fetch_puzzle(args.day, args.year, &path)
+    };
+
+    let width = terminal_size::terminal_size()
+        .map(|(terminal_size::Width(width), _)| width as usize)
+        .unwrap_or(DEFAULT_WIDTH);
+
+    print!("{}", advent_of_code::markdown::render(&puzzle, width));
 }
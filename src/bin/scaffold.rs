@@ -2,113 +2,450 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::aoc_client::{download, get_input_path, get_puzzle_path, AocClient};
+use advent_of_code::{config, extract, template, year_dir};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+/// Used unless another template is passed with `--template`.
+const DEFAULT_TEMPLATE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/day.rs.tmpl");
+const DEFAULT_RETURN_TYPE: &str = "Option<u32>";
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
-}
+const YEAR_TEMPLATE: &str = r###"/*
+ * Solutions for {{year}}, see `src/days/mod.rs`.
+ * `cargo scaffold` adds new days to the `days!` invocation at the bottom of this file, ordered by day.
+ */
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+days! {
 }
+"###;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const YEARS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/days/mod.rs");
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+const BIN_TEMPLATE: &str = r###"use advent_of_code::days::y{{year}}::Day{{day_padded}};
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+fn main() {
+    let input = &advent_of_code::read_input_or_exit({{year}}, {{day}});
+    advent_of_code::solve!(Day{{day_padded}}, 1, input);
+    advent_of_code::solve!(Day{{day_padded}}, 2, input);
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: u16,
+    template: PathBuf,
+    return_type: String,
+    with_parser: bool,
+    overwrite: bool,
+    download: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE_PATH.into()),
+        return_type: args
+            .opt_value_from_str("--return-type")?
+            .unwrap_or_else(|| DEFAULT_RETURN_TYPE.into()),
+        with_parser: args.contains("--with-parser"),
+        overwrite: args.contains("--overwrite"),
+        download: args.contains("--download"),
+        day: args.free_from_str()?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
+/// Renders the module of a day from the template at `args.template`.
+fn render_module(args: &Args) -> Result<String, String> {
+    let template = fs::read_to_string(&args.template)
+        .map_err(|e| format!("could not read template {}: {e}", quoted(&args.template)))?;
+
+    // the title is only known once the puzzle was downloaded.
+    let title = fs::read_to_string(get_puzzle_path(args.year, args.day))
+        .ok()
+        .and_then(|puzzle| extract::title(&puzzle))
+        .unwrap_or_else(|| format!("Day {}", args.day));
+
+    let unsolved = template::unsolved(&args.return_type).ok_or_else(|| {
+        format!(
+            "the return type \"{}\" is neither an `Option` nor a `Result`",
+            args.return_type
+        )
+    })?;
+
+    let (input_type, parse) = if args.with_parser {
+        ("Vec<&'a str>", "input.lines().collect()")
+    } else {
+        ("&'a str", "input")
     };
 
-    let day_padded = format!("{day:02}");
+    let (year, day, day_padded) = (
+        args.year.to_string(),
+        args.day.to_string(),
+        format!("{:02}", args.day),
+    );
+    template::render(
+        &template,
+        &[
+            ("year", &year),
+            ("day", &day),
+            ("day_padded", &day_padded),
+            ("title", &title),
+            ("return_type", &args.return_type),
+            ("unsolved", unsolved),
+            ("input_type", input_type),
+            ("parse", parse),
+        ],
+    )
+    .map_err(|e| format!("could not render template {}: {e}", quoted(&args.template)))
+}
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+/// Adds `entry` at its sorted position to the macro invocation that closes the registry file at `path`, unless it is already there.
+fn register(path: &Path, entry: &str) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    if registry.contains(entry) {
+        return Ok(());
+    }
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+    let registry = insert_sorted(&registry, entry).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "could not find the registry macro invocation",
+        )
+    })?;
+    fs::write(path, registry)
+}
+
+/// Inserts `entry` into the last macro invocation of `registry`, before the first entry that sorts after it.
+///
+/// Days are registered as `dayNN` and years with four digits, so their text sorts like their number.
+fn insert_sorted(registry: &str, entry: &str) -> Option<String> {
+    let end = registry.trim_end().rfind('}')?;
+    let start = registry[..end].rfind("{\n")? + 2;
+
+    let mut position = end;
+    let mut offset = start;
+    for line in registry[start..end].split_inclusive('\n') {
+        if !line.trim().is_empty() && line.trim() > entry.trim() {
+            position = offset;
+            break;
         }
+        offset += line.len();
+    }
+
+    Some(format!(
+        "{}{}{}",
+        &registry[..position],
+        entry,
+        &registry[position..]
+    ))
+}
+
+/// Creates the module of a year that has no solutions yet and registers it.
+fn scaffold_year(year: u16, paths: &Paths) -> Result<(), String> {
+    fs::create_dir_all(&paths.days_dir)
+        .map_err(|e| format!("could not create year directory: {e}"))?;
+    let module = template::render(YEAR_TEMPLATE, &[("year", &year.to_string())])
+        .expect("the year template only uses known placeholders");
+    write_module(&paths.registry, &module, false)?;
+
+    register(&paths.years, &format!("    {year} => y{year},\n"))
+        .map_err(|e| format!("could not register year: {e}"))?;
+    println!("Registered year {year} in {}", quoted(&paths.years));
+    Ok(())
+}
+
+fn write_module(path: &Path, contents: &str, overwrite: bool) -> Result<(), String> {
+    let file = if overwrite {
+        File::create(path)
+    } else {
+        safe_create_file(path)
     };
+    file.and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("could not create module file {}: {e}", quoted(path)))?;
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    println!("Created module file {}", quoted(path));
+    Ok(())
+}
+
+/// `src/bin/<day>.rs`, or `src/bin/<year>-<day>.rs` if the former already runs that day of another year.
+fn bin_path(year: u16, day: u8) -> PathBuf {
+    let bins = root().join("src").join("bin");
+    let path = bins.join(format!("{day:02}.rs"));
+    match fs::read_to_string(&path) {
+        Ok(bin) if !bin.contains(&format!("::y{year}::")) => {
+            bins.join(format!("{year}-{day:02}.rs"))
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+        _ => path,
+    }
+}
+
+/// Creates the binary that runs a single day, unless it already exists.
+fn write_bin(path: &Path, year: u16, day: u8) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    let bin = template::render(
+        BIN_TEMPLATE,
+        &[
+            ("year", &year.to_string()),
+            ("day", &day.to_string()),
+            ("day_padded", &format!("{day:02}")),
+        ],
+    )
+    .expect("the binary template only uses known placeholders");
+    safe_create_file(path)
+        .and_then(|mut file| file.write_all(bin.as_bytes()))
+        .map_err(|e| format!("could not create binary {}: {e}", quoted(path)))?;
+
+    println!("Created binary {}", quoted(path));
+    Ok(())
+}
+
+/// The root of this repository, which every path a scaffold touches is built from.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Quotes `path` for messages, relative to the repository if it is inside of it.
+fn quoted(path: &Path) -> String {
+    format!(
+        "\"{}\"",
+        path.strip_prefix(root()).unwrap_or(path).display()
+    )
+}
+
+/// Every file and directory a scaffold of `day` may create or modify.
+struct Paths {
+    days_dir: PathBuf,
+    module: PathBuf,
+    registry: PathBuf,
+    years: PathBuf,
+    bin: PathBuf,
+    year_dir: PathBuf,
+    input: PathBuf,
+    puzzle: PathBuf,
+    example: PathBuf,
+    part_two_example: PathBuf,
+}
+
+impl Paths {
+    fn new(year: u16, day: u8) -> Self {
+        let days_dir = root().join("src").join("days").join(format!("y{year}"));
+        let year_dir = year_dir(year);
+        Self {
+            module: days_dir.join(format!("day{day:02}.rs")),
+            registry: days_dir.join("mod.rs"),
+            years: PathBuf::from(YEARS_PATH),
+            bin: bin_path(year, day),
+            input: get_input_path(year, day),
+            puzzle: get_puzzle_path(year, day),
+            example: year_dir.join("examples").join(format!("{day:02}.txt")),
+            part_two_example: year_dir.join("examples").join(format!("{day:02}-2.txt")),
+            days_dir,
+            year_dir,
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::take(
+            &[
+                self.module.clone(),
+                self.registry.clone(),
+                self.years.clone(),
+                self.bin.clone(),
+                self.input.clone(),
+                self.puzzle.clone(),
+                self.example.clone(),
+                self.part_two_example.clone(),
+            ],
+            &[
+                self.days_dir.clone(),
+                self.year_dir.clone(),
+                self.year_dir.join("inputs"),
+                self.year_dir.join("examples"),
+                self.year_dir.join("puzzles"),
+            ],
+        )
+    }
+}
+
+/// The state of the files and directories a scaffold may touch, restored if any step fails.
+struct Snapshot {
+    /// Contents of every file before scaffolding, `None` if it did not exist.
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+    /// Directories that did not exist before scaffolding, outermost first.
+    dirs: Vec<PathBuf>,
+}
+
+impl Snapshot {
+    fn take(files: &[PathBuf], dirs: &[PathBuf]) -> Self {
+        Self {
+            files: files
+                .iter()
+                .map(|path| (path.clone(), fs::read(path).ok()))
+                .collect(),
+            dirs: dirs.iter().filter(|dir| !dir.exists()).cloned().collect(),
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    /// Removes created files and directories and puts back the contents of modified files.
+    fn restore(&self) {
+        for (path, contents) in &self.files {
+            let restored = match contents {
+                Some(contents) => {
+                    fs::read(path).is_ok_and(|current| &current == contents)
+                        || fs::write(path, contents).is_ok()
+                }
+                None => !path.exists() || fs::remove_file(path).is_ok(),
+            };
+            if !restored {
+                eprintln!("Failed to restore \"{}\"", path.display());
+            }
         }
+
+        // directories are only removed if they are empty, which leaves anything created in the meantime alone.
+        for dir in self.dirs.iter().rev() {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+fn scaffold(args: &Args, paths: &Paths) -> Result<(), String> {
+    let (day, year) = (args.day, args.year);
+    let day_padded = format!("{day:02}");
+
+    if args.download {
+        // downloading first makes the puzzle title available to the template.
+        let client = AocClient::from_env(year).map_err(|e| format!("could not download: {e}"))?;
+        download(&client, day).map_err(|e| format!("could not download: {e}"))?;
+        println!("---");
+    }
+
+    if !paths.registry.exists() {
+        scaffold_year(year, paths)?;
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let module = render_module(args)?;
+    write_module(&paths.module, &module, args.overwrite)?;
+
+    register(
+        &paths.registry,
+        &format!("    day{day_padded}::Day{day_padded},\n"),
+    )
+    .map_err(|e| format!("could not register day: {e}"))?;
+    println!(
+        "Registered day {} in {}",
+        &day_padded,
+        quoted(&paths.registry)
+    );
+
+    write_bin(&paths.bin, year, day)?;
+
+    for dir in [
+        paths.year_dir.join("inputs"),
+        paths.year_dir.join("examples"),
+    ] {
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("could not create directory {}: {e}", quoted(&dir)))?;
+    }
+
+    if args.download {
+        println!("---");
+        extract::write_examples(year, day, false)
+            .map_err(|e| format!("could not extract examples: {e}"))?;
+        // the tests of the module read the example, so it has to exist even if the puzzle had none.
+        if paths.example.exists() {
+            return Ok(());
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+    } else {
+        create_file(&paths.input).map_err(|e| format!("could not create input file: {e}"))?;
+        println!("Created empty input file {}", quoted(&paths.input));
+    }
+
+    create_file(&paths.example).map_err(|e| format!("could not create example file: {e}"))?;
+    println!("Created empty example file {}", quoted(&paths.example));
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+    };
+
+    let (day, year) = (args.day, args.year);
+    let day_padded = format!("{day:02}");
+    let paths = Paths::new(year, day);
+    let snapshot = paths.snapshot();
+
+    if let Err(e) = scaffold(&args, &paths) {
+        eprintln!("Failed to scaffold day {day}: {e}");
+        snapshot.restore();
+        eprintln!("Rolled back the files created for day {day}.");
+        process::exit(1);
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        &day_padded, year
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted() {
+        let days = "days! {\n    day01::Day01,\n    day03::Day03,\n}\n";
+        assert_eq!(
+            insert_sorted(days, "    day02::Day02,\n"),
+            Some("days! {\n    day01::Day01,\n    day02::Day02,\n    day03::Day03,\n}\n".into())
+        );
+        assert_eq!(
+            insert_sorted(days, "    day10::Day10,\n"),
+            Some("days! {\n    day01::Day01,\n    day03::Day03,\n    day10::Day10,\n}\n".into())
+        );
+        assert_eq!(
+            insert_sorted("days! {\n}\n", "    day01::Day01,\n"),
+            Some("days! {\n    day01::Day01,\n}\n".into())
+        );
+
+        let years = "macro_rules! years {\n}\n\nyears! {\n    2016 => y2016,\n}\n";
+        assert_eq!(
+            insert_sorted(years, "    2015 => y2015,\n"),
+            Some(
+                "macro_rules! years {\n}\n\nyears! {\n    2015 => y2015,\n    2016 => y2016,\n}\n"
+                    .into()
+            )
+        );
+        assert_eq!(insert_sorted("", "    2015 => y2015,\n"), None);
+    }
+}
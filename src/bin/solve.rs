@@ -0,0 +1,46 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{config, run_part};
+use std::process;
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo solve 7`");
+            process::exit(1);
+        }
+    };
+
+    let Some(day) = advent_of_code::days::registry(args.year)
+        .into_iter()
+        .find(|day| day.day == args.day)
+    else {
+        eprintln!(
+            "Day {} of {} is not registered. Run `cargo scaffold {} --year {}` to create it.",
+            args.day, args.year, args.day, args.year
+        );
+        process::exit(1);
+    };
+
+    let input = advent_of_code::read_input_or_exit(day.year, day.day);
+    run_part(day.year, day.day, 1, day.part_one, &input);
+    run_part(day.year, day.day, 2, day.part_two, &input);
+}
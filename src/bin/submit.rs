@@ -0,0 +1,154 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc_client::{AocClient, Hint, Verdict};
+use advent_of_code::config;
+use advent_of_code::submissions::{Submission, SubmissionLog};
+use advent_of_code::{solve_part, PartResult, Status};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+/// Runs the registered solution for `day` against its input, `None` if there is no solution for the day.
+fn solve(year: u16, day: u8, part: u8) -> Option<PartResult> {
+    let solution = advent_of_code::days::registry(year)
+        .into_iter()
+        .find(|solution| solution.day == day)?;
+    let input = advent_of_code::read_input_or_exit(year, day);
+    let solver = match part {
+        1 => solution.part_one,
+        _ => solution.part_two,
+    };
+
+    Some(solve_part(year, day, part, solver, &input))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    if args.part != 1 && args.part != 2 {
+        eprintln!("Part must be 1 or 2. example: `cargo submit 7 1`");
+        process::exit(1);
+    }
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match solve(args.year, args.day, args.part) {
+            Some(PartResult {
+                answer: Some(answer),
+                ..
+            }) => {
+                println!("Solved day {} part {}: {answer}", args.day, args.part);
+                answer
+            }
+            Some(PartResult {
+                status: Status::Failed,
+                error: Some(e),
+                ..
+            }) => {
+                eprintln!("Day {} part {} failed: {e}", args.day, args.part);
+                process::exit(1);
+            }
+            Some(PartResult {
+                status: Status::Panicked,
+                error: Some(e),
+                ..
+            }) => {
+                eprintln!("Day {} part {} panicked: {e}", args.day, args.part);
+                process::exit(1);
+            }
+            _ => {
+                eprintln!("Day {} part {} is not solved yet.", args.day, args.part);
+                process::exit(1);
+            }
+        },
+    };
+
+    let client = match AocClient::from_env(args.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut log = match SubmissionLog::load() {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("Failed to read submissions: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = log.check(client.year(), args.day, args.part, &answer) {
+        eprintln!("Not submitting \"{answer}\": {refusal}");
+        process::exit(1);
+    }
+
+    println!(
+        "Submitting \"{answer}\" for day {}, {} part {}...",
+        args.day,
+        client.year(),
+        args.part
+    );
+    let result = client.submit(args.day, args.part, &answer);
+
+    if let Some(submission) = Submission::new(client.year(), args.day, args.part, &answer, &result)
+    {
+        if let Err(e) = log.record(submission) {
+            eprintln!("Failed to record submission: {e}");
+        }
+    }
+
+    println!("---");
+    match result {
+        Ok(Verdict::Correct) => {
+            println!("🎄 That's the right answer!");
+        }
+        Ok(Verdict::Incorrect { hint, wait }) => {
+            match hint {
+                Some(Hint::TooHigh) => println!("❌ That's not the right answer, it is too high."),
+                Some(Hint::TooLow) => println!("❌ That's not the right answer, it is too low."),
+                None => println!("❌ That's not the right answer."),
+            }
+            if let Some(wait) = wait {
+                println!("Wait {}s before submitting again.", wait.as_secs());
+            }
+            process::exit(1);
+        }
+        Ok(Verdict::AlreadyCompleted) => {
+            println!("This part is already completed.");
+        }
+        Ok(Verdict::Unknown(message)) => {
+            println!("{message}");
+        }
+        Err(e) => {
+            eprintln!("Failed to submit answer: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::history::{compare, History, Run};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+/// Parts that got slower by more than this many percent are flagged, unless overridden with `--threshold`.
+const DEFAULT_THRESHOLD: f64 = 10_f64;
+
+struct Args {
+    compare: bool,
+    baseline: Option<String>,
+    threshold: f64,
+    tag: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        compare: args.contains("--compare"),
+        baseline: args.opt_value_from_str("--baseline")?,
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(DEFAULT_THRESHOLD),
+        tag: args.opt_value_from_str("--tag")?,
+    })
+}
+
+fn describe(run: &Run) -> String {
+    let mut description = run
+        .commit
+        .as_deref()
+        .unwrap_or("unknown commit")
+        .to_string();
+    if let Some(tag) = &run.tag {
+        description.push_str(&format!(" ({tag})"));
+    }
+    description
+}
+
+fn print_runs(history: &History) {
+    for run in &history.runs {
+        println!(
+            "{} [{}] {} parts, total: {:.2}ms",
+            describe(run),
+            run.setup(),
+            run.parts.len(),
+            run.total().as_secs_f64() * 1000_f64
+        );
+    }
+}
+
+/// Prints the deltas of the latest run, returning whether any part regressed.
+fn print_comparison(baseline: &Run, latest: &Run, threshold: f64) -> bool {
+    println!(
+        "Comparing {} against {}",
+        describe(latest),
+        describe(baseline)
+    );
+    println!("---");
+
+    let deltas = compare(baseline, latest, threshold);
+    for delta in &deltas {
+        let flag = if delta.regressed { " ⚠️" } else { "" };
+        println!(
+            "{} day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%){flag}",
+            delta.year, delta.day, delta.part, delta.baseline, delta.latest, delta.change
+        );
+    }
+
+    let regressions = deltas.iter().filter(|delta| delta.regressed).count();
+    println!("---");
+    if regressions > 0 {
+        println!("{ANSI_BOLD}{regressions} part(s) got more than {threshold}% slower.{ANSI_RESET}");
+    } else {
+        println!("🎄 No part got more than {threshold}% slower.");
+    }
+
+    regressions > 0
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut history = match History::load() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    };
+
+    if history.runs.is_empty() {
+        eprintln!("No timings recorded yet. Run `cargo all --release` first.");
+        process::exit(1);
+    }
+
+    if let Some(tag) = args.tag {
+        if let Some(run) = history.runs.last_mut() {
+            run.tag = Some(tag);
+        }
+        if let Err(e) = history.save() {
+            eprintln!("Failed to write timing history: {e}");
+            process::exit(1);
+        }
+        println!("Tagged {}", describe(history.runs.last().unwrap()));
+        return;
+    }
+
+    if !args.compare {
+        print_runs(&history);
+        return;
+    }
+
+    let (latest, previous) = history.runs.split_last().unwrap();
+    let baseline = match &args.baseline {
+        Some(name) => history.find(name),
+        None => previous.iter().rev().find(|run| run.is_comparable(latest)),
+    };
+
+    let Some(baseline) = baseline else {
+        match args.baseline {
+            Some(name) => {
+                eprintln!("Could not find a run tagged \"{name}\" or of commit \"{name}\".")
+            }
+            None => eprintln!(
+                "No earlier run of the same setup ({}) recorded, there is nothing to compare against yet.",
+                latest.setup()
+            ),
+        }
+        process::exit(1);
+    };
+
+    if !baseline.is_comparable(latest) {
+        eprintln!(
+            "Cannot compare runs of different setups: {} was made with {}, {} with {}.",
+            describe(baseline),
+            baseline.setup(),
+            describe(latest),
+            latest.setup()
+        );
+        process::exit(1);
+    }
+
+    if print_comparison(baseline, latest, args.threshold) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,351 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{config, year_dir, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::PathBuf,
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Compiler errors beyond this many lines are cut off, the first error is usually the one to fix.
+const MAX_ERROR_LINES: usize = 20;
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(config().year),
+        day: args.free_from_str()?,
+    })
+}
+
+/// The outcome of a part as reported by `cargo solve --format json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Answer {
+    part: u8,
+    /// The answer, or the status of a part without one, e.g. `not solved`.
+    value: String,
+    correct: Option<bool>,
+}
+
+/// Summary of a `cargo test` run.
+#[derive(Debug, PartialEq, Eq)]
+enum TestOutcome {
+    Passed(usize),
+    Failed { passed: usize, failed: Vec<String> },
+    BuildFailed(Vec<String>),
+}
+
+fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let year_dir = year_dir(year);
+    let mut files = vec![
+        root.join(format!("src/days/y{year}/day{day:02}.rs")),
+        root.join("src/helpers.rs"),
+        year_dir.join("inputs").join(format!("{day:02}.txt")),
+        year_dir.join("examples").join(format!("{day:02}.txt")),
+        year_dir.join("examples").join(format!("{day:02}-2.txt")),
+    ];
+    if let Ok(entries) = fs::read_dir(root.join("src/helpers")) {
+        files.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+    }
+    files
+}
+
+/// Modification times of the watched files, missing files are left out.
+fn snapshot(files: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/// Environment variables `cargo run` sets for the running crate.
+const CRATE_ENV_PREFIXES: [&str; 5] = [
+    "CARGO_PKG_",
+    "CARGO_MANIFEST_",
+    "CARGO_CRATE_NAME",
+    "CARGO_BIN_NAME",
+    "CARGO_PRIMARY_PACKAGE",
+];
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command.current_dir(env!("CARGO_MANIFEST_DIR"));
+    // build scripts of dependencies track some of these, inheriting them would rebuild everything on every run.
+    for (key, _) in env::vars() {
+        if CRATE_ENV_PREFIXES
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+        {
+            command.env_remove(key);
+        }
+    }
+    command
+}
+
+fn run_tests(year: u16, day: u8) -> Result<TestOutcome, String> {
+    let output = cargo()
+        .args(["test", "--lib", "--"])
+        .arg(format!("days::y{year}::day{day:02}::"))
+        .output()
+        .map_err(|e| format!("could not run `cargo test`: {e}"))?;
+
+    Ok(parse_test_output(
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+    ))
+}
+
+fn parse_test_output(stdout: &str, stderr: &str) -> TestOutcome {
+    let Some(summary) = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("test result: "))
+    else {
+        let errors = stderr
+            .lines()
+            .skip_while(|line| !line.starts_with("error"))
+            .take(MAX_ERROR_LINES)
+            .map(String::from)
+            .collect();
+        return TestOutcome::BuildFailed(errors);
+    };
+
+    let count = |label: &str| {
+        summary
+            .split(';')
+            .find_map(|field| {
+                field
+                    .strip_suffix(label)?
+                    .split_whitespace()
+                    .last()?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or(0)
+    };
+    let failed: Vec<String> = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test ")?.strip_suffix(" ... FAILED"))
+        .map(|name| name.rsplit("::").next().unwrap_or(name).to_string())
+        .collect();
+
+    match count("failed") {
+        0 => TestOutcome::Passed(count("passed")),
+        _ => TestOutcome::Failed {
+            passed: count("passed"),
+            failed,
+        },
+    }
+}
+
+fn run_solution(year: u16, day: u8) -> Result<Vec<Answer>, String> {
+    let output = cargo()
+        .args(["run", "--quiet", "--release", "--bin", "solve", "--"])
+        .arg(day.to_string())
+        .args(["--year", &year.to_string(), "--format", "json"])
+        .output()
+        .map_err(|e| format!("could not run `cargo solve`: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().last().unwrap_or_default().to_string());
+    }
+
+    Ok(parse_answers(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_answers(stdout: &str) -> Vec<Answer> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|result| {
+            let value = match (&result["answer"], &result["error"]) {
+                (serde_json::Value::String(answer), _) => answer.clone(),
+                (_, serde_json::Value::String(error)) => {
+                    format!("{}: {error}", result["status"].as_str()?)
+                }
+                _ => result["status"].as_str()?.replace('_', " "),
+            };
+            Some(Answer {
+                part: result["part"].as_u64()? as u8,
+                value,
+                correct: result["correct"].as_bool(),
+            })
+        })
+        .collect()
+}
+
+fn print_tests(outcome: &TestOutcome) {
+    match outcome {
+        TestOutcome::Passed(passed) => println!("tests: ✅ {passed} passed"),
+        TestOutcome::Failed { passed, failed } => println!(
+            "tests: ❌ {} failed ({}), {passed} passed",
+            failed.len(),
+            failed.join(", ")
+        ),
+        TestOutcome::BuildFailed(errors) => {
+            println!("tests: ❌ build failed");
+            for line in errors {
+                println!("  {line}");
+            }
+        }
+    }
+}
+
+/// Prints the answers, pointing out those that differ from the previous run.
+fn print_answers(answers: &[Answer], previous: &[Answer]) {
+    for answer in answers {
+        let check = match answer.correct {
+            Some(true) => " ✅",
+            Some(false) => " ❌",
+            None => "",
+        };
+        let change = match previous.iter().find(|prev| prev.part == answer.part) {
+            Some(prev) if prev.value != answer.value => {
+                format!(" {ANSI_ITALIC}(was: {}){ANSI_RESET}", prev.value)
+            }
+            Some(_) => format!(" {ANSI_ITALIC}(unchanged){ANSI_RESET}"),
+            None => String::new(),
+        };
+        println!("part {}: {}{check}{change}", answer.part, answer.value);
+    }
+}
+
+/// Runs the tests and, if they build, the solution, returning the answers to compare the next run against.
+fn run(year: u16, day: u8, previous: &[Answer]) -> Vec<Answer> {
+    let tests = match run_tests(year, day) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            return previous.to_vec();
+        }
+    };
+    print_tests(&tests);
+
+    // a solution that does not build has no answers, the previous ones stay the baseline.
+    if matches!(tests, TestOutcome::BuildFailed(_)) {
+        return previous.to_vec();
+    }
+
+    match run_solution(year, day) {
+        Ok(answers) => {
+            print_answers(&answers, previous);
+            answers
+        }
+        Err(e) => {
+            println!("solution: ❌ {e}");
+            previous.to_vec()
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let (year, day) = (args.year, args.day);
+    let files = watched_files(year, day);
+    if !files[0].exists() {
+        eprintln!(
+            "Could not find \"{}\". Run `cargo scaffold {day} --year {year}` to create it.",
+            files[0].display()
+        );
+        process::exit(1);
+    }
+
+    println!("👀 Watching day {day:02} of {year}, press Ctrl-C to stop.");
+    let mut seen = snapshot(&files);
+    let mut answers = run(year, day, &[]);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = snapshot(&files);
+        if current == seen {
+            continue;
+        }
+
+        let changed: Vec<String> = files
+            .iter()
+            .filter(|path| current.get(*path) != seen.get(*path))
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        seen = current;
+
+        println!("---");
+        println!("{ANSI_BOLD}🔁 {}{ANSI_RESET}", changed.join(", "));
+        answers = run(year, day, &answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_output() {
+        let stdout = "\nrunning 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 61 filtered out; finished in 0.00s\n";
+        assert_eq!(parse_test_output(stdout, ""), TestOutcome::Passed(2));
+
+        let stdout = "\nrunning 2 tests\ntest days::y2015::day03::tests::test_part_two ... FAILED\n.\nfailures:\n\ntest result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 61 filtered out; finished in 0.00s\n";
+        assert_eq!(
+            parse_test_output(stdout, ""),
+            TestOutcome::Failed {
+                passed: 1,
+                failed: vec!["test_part_two".into()]
+            }
+        );
+
+        let stderr = "   Compiling advent_of_code v0.8.0\nerror[E0425]: cannot find value `x` in this scope\n --> src/days/y2015/day03.rs:4:5\n";
+        assert_eq!(
+            parse_test_output("", stderr),
+            TestOutcome::BuildFailed(vec![
+                "error[E0425]: cannot find value `x` in this scope".into(),
+                " --> src/days/y2015/day03.rs:4:5".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let stdout = r#"{"year":2015,"day":3,"part":1,"answer":"2592","elapsed_ns":1,"status":"solved","expected":"2592","correct":true}
+{"year":2015,"day":3,"part":2,"answer":null,"elapsed_ns":1,"status":"not_solved"}"#;
+        assert_eq!(
+            parse_answers(stdout),
+            vec![
+                Answer {
+                    part: 1,
+                    value: "2592".into(),
+                    correct: Some(true)
+                },
+                Answer {
+                    part: 2,
+                    value: "not solved".into(),
+                    correct: None
+                }
+            ]
+        );
+    }
+}
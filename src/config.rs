@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, path::PathBuf, process, str::FromStr, sync::OnceLock, time::Duration};
+
+use crate::{aoc_client::latest_event_year, days, InputSource};
+
+/// Time spent measuring each part in benchmark mode, unless overridden with `--bench-time`.
+const DEFAULT_BENCH_TIME: Duration = Duration::from_millis(500);
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable, ANSI-decorated output.
+    #[default]
+    Text,
+    /// One JSON record per solved part.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown format \"{s}\", expected \"text\" or \"json\""
+            )),
+        }
+    }
+}
+
+/// Options shared by the day binaries and `cargo all`.
+///
+/// Flags take precedence over their environment variable counterparts.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// `--year <year>` or `AOC_YEAR`, defaults to the most recent year with registered solutions.
+    pub year: u16,
+    /// `--format <text|json>` or `AOC_FORMAT`.
+    pub format: Format,
+    /// `--bench [--bench-time <ms>]`: time budget per part in benchmark mode.
+    pub bench: Option<Duration>,
+    /// `--verify`: exit with a non-zero status if any answer does not match `src/<year>/answers/NN.toml`.
+    pub verify: bool,
+    /// `--input <path|->`: read the puzzle input from a file or stdin instead of `src/<year>/inputs`.
+    pub input: Option<InputSource>,
+    /// `AOC_INPUT_DIR`: directory to read `NN.txt` puzzle inputs from instead of `src/<year>/inputs`.
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    fn from_env() -> Result<Self, String> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let year = match args
+            .opt_value_from_str::<_, u16>(["-y", "--year"])
+            .map_err(|e| e.to_string())?
+        {
+            Some(year) => year,
+            None => match env::var("AOC_YEAR") {
+                Ok(year) => year
+                    .parse()
+                    .map_err(|_| format!("invalid AOC_YEAR \"{year}\""))?,
+                Err(_) => days::YEARS
+                    .last()
+                    .copied()
+                    .unwrap_or_else(latest_event_year),
+            },
+        };
+
+        let format = match args
+            .opt_value_from_str::<_, Format>("--format")
+            .map_err(|e| e.to_string())?
+        {
+            Some(format) => format,
+            None => match env::var("AOC_FORMAT") {
+                Ok(format) => format.parse()?,
+                Err(_) => Format::default(),
+            },
+        };
+
+        let bench_time = args
+            .opt_value_from_str::<_, u64>("--bench-time")
+            .map_err(|e| e.to_string())?
+            .map(Duration::from_millis);
+        let bench = if args.contains("--bench") {
+            Some(bench_time.unwrap_or(DEFAULT_BENCH_TIME))
+        } else {
+            None
+        };
+
+        let verify = args.contains("--verify");
+
+        let input = args
+            .opt_value_from_str::<_, InputSource>("--input")
+            .map_err(|e| e.to_string())?;
+        let input_dir = env::var_os("AOC_INPUT_DIR").map(PathBuf::from);
+
+        Ok(Self {
+            year,
+            format,
+            bench,
+            verify,
+            input,
+            input_dir,
+        })
+    }
+
+    /// Whether puzzle inputs are read from somewhere else than `src/<year>/inputs`.
+    ///
+    /// Known answers only apply to the inputs in `src/<year>/inputs`.
+    pub fn has_custom_input(&self) -> bool {
+        self.input.is_some() || self.input_dir.is_some()
+    }
+}
+
+/// Returns the configuration for this process, reading it from the command line and environment on first use.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    })
+}
@@ -0,0 +1,41 @@
+/*
+ * Every solution is registered here so `cargo all` and `cargo solve` can run it in-process.
+ * Each year has its own module with a `days!` invocation at the bottom, listing its solutions.
+ * `cargo scaffold` adds new days there and new years to the `years!` invocation at the bottom of this file, keeping both in order.
+ */
+use crate::Day;
+
+macro_rules! days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(
+            mod $module;
+            pub use $module::$solution;
+        )*
+
+        /// All registered solutions of this year, ordered by day.
+        pub fn registry() -> Vec<crate::Day> {
+            vec![$(crate::Day::of::<$solution>()),*]
+        }
+    };
+}
+
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every year with registered solutions, in ascending order.
+        pub const YEARS: &[u16] = &[$($year),*];
+
+        /// All registered solutions of `year`, ordered by day.
+        pub fn registry(year: u16) -> Vec<Day> {
+            match year {
+                $($year => $module::registry(),)*
+                _ => vec![],
+            }
+        }
+    };
+}
+
+years! {
+    2015 => y2015,
+}
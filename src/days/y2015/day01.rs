@@ -0,0 +1,75 @@
+use std::convert::Infallible;
+
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type ParseError = Infallible;
+    type PartOne = Option<i32>;
+    type PartTwo = Option<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(input.chars().fold(0, |acc, c| match c {
+            '(' => acc + 1,
+            ')' => acc - 1,
+            _ => acc,
+        }))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut floor = 0;
+        for (i, c) in input.chars().enumerate() {
+            match c {
+                '(' => {
+                    floor += 1;
+                }
+                ')' => {
+                    floor -= 1;
+                }
+                _ => (),
+            }
+
+            if floor < 0 {
+                return Some(i as i32 + 1);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Day01::solve_part_one("(())"), Some(0));
+        assert_eq!(Day01::solve_part_one("()()"), Some(0));
+
+        assert_eq!(Day01::solve_part_one("((("), Some(3));
+        assert_eq!(Day01::solve_part_one("(()(()("), Some(3));
+        assert_eq!(Day01::solve_part_one("))((((("), Some(3));
+
+        assert_eq!(Day01::solve_part_one("())"), Some(-1));
+        assert_eq!(Day01::solve_part_one("))("), Some(-1));
+
+        assert_eq!(Day01::solve_part_one(")))"), Some(-3));
+        assert_eq!(Day01::solve_part_one(")())())"), Some(-3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Day01::solve_part_two(")"), Some(1));
+        assert_eq!(Day01::solve_part_two("()())"), Some(5));
+    }
+}
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use crate::{
+    helpers::scan::{parse_lines, ScanError},
+    scan, Solution,
+};
+
+pub struct PresentBox {
+    length: u32,
+    width: u32,
+    height: u32,
+}
+
+impl PresentBox {
+    fn calculate_paper_required(&self) -> u32 {
+        let sides = [
+            (self.length * self.width),
+            (self.width * self.height),
+            (self.height * self.length),
+        ];
+        let area = sides.iter().sum::<u32>() * 2;
+        let smallest_side = *sides.iter().min().unwrap();
+        area + smallest_side
+    }
+
+    fn calculate_ribbon_required(&self) -> u32 {
+        let mut sides = [self.length, self.width, self.height];
+        sides.sort();
+        let wrapping_ribbon = sides.iter().take(2).map(|side| *side * 2).sum::<u32>();
+        let bow_ribbon = sides.iter().product::<u32>();
+        wrapping_ribbon + bow_ribbon
+    }
+}
+
+impl FromStr for PresentBox {
+    type Err = ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (length, width, height) = scan!("{}x{}x{}", s => u32, u32, u32)?;
+        Ok(Self {
+            length,
+            width,
+            height,
+        })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<PresentBox>;
+    type ParseError = ScanError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(
+            input
+                .iter()
+                .map(PresentBox::calculate_paper_required)
+                .sum(),
+        )
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        Some(
+            input
+                .iter()
+                .map(PresentBox::calculate_ribbon_required)
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Day02::solve_part_one("2x3x4"), Some(58));
+        assert_eq!(Day02::solve_part_one("1x1x10"), Some(43));
+        assert_eq!(
+            Day02::parse("2x3x4\n1x1").err().map(|e| e.to_string()),
+            Some("line 2, column 3: expected \"x\", found \"1\"".into())
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Day02::solve_part_two("2x3x4"), Some(34));
+        assert_eq!(Day02::solve_part_two("1x1x10"), Some(14));
+    }
+}
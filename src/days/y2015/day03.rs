@@ -0,0 +1,77 @@
+use crate::{
+    helpers::{
+        geom::{parse_directions, Direction, ParseDirectionError, Point},
+        grid::HashGrid,
+    },
+    Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Direction>;
+    type ParseError = ParseDirectionError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        parse_directions(input.trim_end())
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        let mut position = Point::default();
+        let mut visited = HashGrid::new();
+        visited.insert(position.into(), ());
+
+        for &direction in input {
+            position = position.step(direction);
+            visited.insert(position.into(), ());
+        }
+
+        Some(visited.len() as u32)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let (mut santa, mut robo_santa) = (Point::default(), Point::default());
+        let mut visited = HashGrid::new();
+        visited.insert(santa.into(), ());
+
+        for (i, &direction) in input.iter().enumerate() {
+            let santa = if i % 2 == 0 {
+                &mut santa
+            } else {
+                &mut robo_santa
+            };
+            *santa = santa.step(direction);
+            visited.insert((*santa).into(), ());
+        }
+
+        Some(visited.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Day03::solve_part_one(">"), Some(2));
+        assert_eq!(Day03::solve_part_one("^>v<"), Some(4));
+        assert_eq!(Day03::solve_part_one("^v^v^v^v^v"), Some(2));
+        assert_eq!(
+            Day03::parse("^x").err(),
+            Some(ParseDirectionError("x".into()))
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Day03::solve_part_two("^v"), Some(3));
+        assert_eq!(Day03::solve_part_two("^>v<"), Some(3));
+        assert_eq!(Day03::solve_part_two("^v^v^v^v^v"), Some(11));
+    }
+}
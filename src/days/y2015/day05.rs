@@ -0,0 +1,102 @@
+use std::{collections::HashMap, convert::Infallible};
+
+use crate::Solution;
+
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+    type ParseError = Infallible;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(
+            input
+                .lines()
+                .filter(|line| {
+                    let vowels = line.chars().filter(|c| VOWELS.contains(c)).count();
+                    if vowels < 3 {
+                        return false;
+                    }
+                    let mut double = false;
+                    for window in line.chars().collect::<Vec<_>>().windows(2) {
+                        match window {
+                            ['a', 'b'] | ['c', 'd'] | ['p', 'q'] | ['x', 'y'] => return false,
+                            [a, b] if a == b => {
+                                double = true;
+                            }
+                            _ => (),
+                        }
+                    }
+                    double
+                })
+                .count(),
+        )
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        Some(
+            input
+                .lines()
+                .filter(|line| {
+                    let line = line.chars().collect::<Vec<_>>();
+
+                    let mut map = HashMap::new();
+                    for (i, window) in line.windows(2).enumerate() {
+                        match window {
+                            [a, b] => {
+                                map.entry((*a, *b))
+                                    .and_modify(|curr: &mut Vec<usize>| curr.push(i))
+                                    .or_insert(vec![i]);
+                            }
+                            _ => todo!(),
+                        }
+                    }
+                    let contains_pattern = map
+                        .values()
+                        .filter(|v| v.len() > 1)
+                        .any(|v| {
+                            let first = v.first().unwrap();
+                            v.iter().any(|i| i - first > 1)
+                        });
+
+                    let repeat_surrounding = line
+                        .windows(3)
+                        .any(|window| matches!(window, [a, _, b] if a == b));
+
+                    contains_pattern && repeat_surrounding
+                })
+                .count(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file_part(2015, "examples", 5, 1).unwrap();
+        assert_eq!(Day05::solve_part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file_part(2015, "examples", 5, 2).unwrap();
+        assert_eq!(Day05::solve_part_two(&input), Some(2));
+
+        let input = "xxxddetvrlpzsfpq";
+        assert_eq!(Day05::solve_part_two(input), Some(0));
+    }
+}
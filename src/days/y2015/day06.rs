@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use crate::{
+    helpers::{
+        grid::Grid,
+        scan::{parse_lines, ScanError},
+    },
+    scan, Solution,
+};
+
+const SIZE: usize = 1000;
+
+#[derive(Debug)]
+pub enum Instruction {
+    TurnOn((usize, usize), (usize, usize)),
+    Toggle((usize, usize), (usize, usize)),
+    TurnOff((usize, usize), (usize, usize)),
+}
+
+impl Instruction {
+    fn execute(&self, grid: &mut Grid<u8>, bit: bool) {
+        let (&start, &finish) = match self {
+            Instruction::TurnOn(start, finish) => (start, finish),
+            Instruction::Toggle(start, finish) => (start, finish),
+            Instruction::TurnOff(start, finish) => (start, finish),
+        };
+
+        for row in grid.rect_rows_mut(start, finish) {
+            for light in row.iter_mut() {
+                match self {
+                    Instruction::TurnOn(_, _) => {
+                        if bit {
+                            *light = 1;
+                        } else {
+                            *light += 1;
+                        }
+                    }
+                    Instruction::Toggle(_, _) => {
+                        if bit {
+                            *light = if *light == 0 { 1 } else { 0 };
+                        } else {
+                            *light += 2;
+                        }
+                    }
+                    Instruction::TurnOff(_, _) => {
+                        if bit {
+                            *light = 0;
+                        } else {
+                            *light = light.checked_sub(1).unwrap_or(0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (instruction, pattern): (fn(_, _) -> Self, _) = if s.starts_with("turn on") {
+            (Self::TurnOn, "turn on {},{} through {},{}")
+        } else if s.starts_with("toggle") {
+            (Self::Toggle, "toggle {},{} through {},{}")
+        } else {
+            // anything else is reported as a mismatch against the last instruction.
+            (Self::TurnOff, "turn off {},{} through {},{}")
+        };
+
+        let (x0, y0, x1, y1) = scan!(pattern, s => usize, usize, usize, usize)?;
+        Ok(instruction((x0, y0), (x1, y1)))
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Instruction>;
+    type ParseError = ScanError;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        let mut grid = Grid::new(SIZE, SIZE, 0_u8);
+
+        for instruction in input {
+            instruction.execute(&mut grid, true);
+        }
+
+        Some(grid.values().filter(|&&active| active == 1).count())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut grid = Grid::new(SIZE, SIZE, 0_u8);
+
+        for instruction in input {
+            instruction.execute(&mut grid, false);
+        }
+
+        Some(grid.values().map(|&light| light as u32).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 6).unwrap();
+        assert_eq!(Day06::solve_part_one(&input), Some(998_996));
+        assert_eq!(
+            Day06::parse("toggle 0,0 through 9,x")
+                .err()
+                .map(|e| e.to_string()),
+            Some("line 1, column 22: expected an unsigned integer, found \"x\"".into())
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 6).unwrap();
+        assert_eq!(Day06::solve_part_two(&input), Some(1_001_996));
+
+        assert_eq!(Day06::solve_part_two("turn on 0,0 through 0,0"), Some(1));
+
+        assert_eq!(Day06::solve_part_two("toggle 0,0 through 999,999"), Some(2_000_000));
+    }
+}
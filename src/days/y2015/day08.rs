@@ -0,0 +1,103 @@
+use std::convert::Infallible;
+
+use crate::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Input<'a> = &'a str;
+    type ParseError = Infallible;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(
+            input
+                .lines()
+                .map(|line| {
+                    let length = line.len() as u32;
+                    let line = &mut line[1..(line.len() - 1)].chars();
+                    let mut characters: u32 = 0;
+                    while let Some(c) = line.next() {
+                        match c {
+                            '\\' => match line.next() {
+                                Some('\\') | Some('"') => {
+                                    characters += 1;
+                                }
+                                Some('x') => {
+                                    line.next();
+                                    line.next();
+
+                                    characters += 1;
+                                }
+                                _ => unreachable!(),
+                            },
+                            _ => {
+                                characters += 1;
+                            }
+                        }
+                    }
+                    length - characters
+                })
+                .sum(),
+        )
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        Some(
+            input
+                .lines()
+                .map(|line| {
+                    let original_characters = line.len() as u32;
+                    let line = &mut line[1..(line.len() - 1)].chars();
+                    let mut characters: u32 = 6;
+                    while let Some(c) = line.next() {
+                        match c {
+                            '\\' => match line.next() {
+                                Some('\\') | Some('"') => {
+                                    characters += 4;
+                                }
+                                Some('x') => {
+                                    line.next();
+                                    line.next();
+
+                                    characters += 5;
+                                }
+                                _ => unreachable!(),
+                            },
+                            _ => {
+                                characters += 1;
+                            }
+                        }
+                    }
+
+                    characters - original_characters
+                })
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 8).unwrap();
+        assert_eq!(Day08::solve_part_one(&input), Some(12));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 8).unwrap();
+        assert_eq!(Day08::solve_part_two(&input), Some(19));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    helpers::{
+        graph::AdjacencyMatrix,
+        scan::{parse_lines, ScanError},
+    },
+    scan, Solution,
+};
+
+/// Returns the shortest or longest route that visits every city once, depending on `pick`.
+fn route_length(distances: &AdjacencyMatrix<u32>, pick: fn(u32, u32) -> u32) -> Option<u32> {
+    let cities = distances.len();
+    // best[visited][last] is the length of the best route through the `visited` bitmask that ends in `last`.
+    let mut best = vec![vec![None; cities]; 1 << cities];
+    for city in 0..cities {
+        best[1 << city][city] = Some(0);
+    }
+
+    for visited in 1..best.len() {
+        for last in 0..cities {
+            let Some(length) = best[visited][last] else {
+                continue;
+            };
+            for (next, &distance) in distances.neighbours(last) {
+                if visited & 1 << next != 0 {
+                    continue;
+                }
+                let route = &mut best[visited | 1 << next][next];
+                *route = Some(route.map_or(length + distance, |route| {
+                    pick(route, length + distance)
+                }));
+            }
+        }
+    }
+
+    best.last()?.iter().flatten().copied().reduce(pick)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Input<'a> = AdjacencyMatrix<u32>;
+    type ParseError = ScanError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        let distances = parse_lines(input, |line| scan!("{} to {} = {}", line => &str, &str, u32))?;
+        let (_, distances) = AdjacencyMatrix::undirected(distances);
+        Ok(distances)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        route_length(input, u32::min)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        route_length(input, u32::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 9).unwrap();
+        assert_eq!(Day09::solve_part_one(&input), Some(605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 9).unwrap();
+        assert_eq!(Day09::solve_part_two(&input), Some(982));
+    }
+}
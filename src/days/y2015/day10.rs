@@ -0,0 +1,68 @@
+use std::convert::Infallible;
+
+use itertools::Itertools;
+
+use crate::Solution;
+
+fn group_by_chars(input: &str) -> Vec<String> {
+    input
+        .chars()
+        .group_by(|c| *c)
+        .into_iter()
+        .map(|(_, group)| group.collect::<String>())
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Input<'a> = &'a str;
+    type ParseError = Infallible;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        let mut result: String = input.to_string();
+
+        for _ in 0..40 {
+            let groups = group_by_chars(&result);
+
+            result = groups
+                .iter()
+                .map(|group| {
+                    let length = group.len();
+                    let character = group.chars().next().unwrap();
+                    format!("{length}{character}")
+                })
+                .collect();
+        }
+
+        Some(result.len())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut result: String = input.to_string();
+
+        for _ in 0..50 {
+            let groups = group_by_chars(&result);
+
+            result = groups
+                .iter()
+                .map(|group| {
+                    let length = group.len();
+                    let character = group.chars().next().unwrap();
+                    format!("{length}{character}")
+                })
+                .collect();
+        }
+
+        Some(result.len())
+    }
+}
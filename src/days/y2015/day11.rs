@@ -0,0 +1,149 @@
+use std::{collections::HashMap, convert::Infallible};
+
+use itertools::Itertools;
+
+use crate::Solution;
+
+struct Password(Vec<char>);
+
+impl Password {
+    fn new(input: &str) -> Self {
+        let chars = input.chars().collect();
+        Self(chars)
+    }
+
+    fn next_password(&mut self) {
+        let Self(chars) = self;
+
+        let mut index = chars.len() - 1;
+
+        loop {
+            let current = chars[index];
+            let next = current as u8 + 1;
+            if next <= b'z' {
+                chars[index] = next as char;
+                break;
+            }
+            chars[index] = 'a';
+            index -= 1;
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        let Self(chars) = self;
+
+        let ascending_pattern = chars.iter().tuple_windows().any(|(&x, &y, &z)| {
+            let x = x as u32;
+            let y = y as u32;
+            let z = z as u32;
+            x + 1 == y && y + 1 == z
+        });
+
+        let valid_letters = chars.iter().all(|&c| c != 'i' && c != 'o' && c != 'l');
+
+        let mut map = HashMap::new();
+        for (i, (&x, &y)) in chars.iter().tuple_windows().enumerate() {
+            if x == y {
+                map.entry((x, y))
+                    .and_modify(|v: &mut Vec<usize>| {
+                        v.push(i);
+                    })
+                    .or_insert_with(|| vec![i]);
+            }
+        }
+        let mut pair_indexes = map.values().flatten().collect::<Vec<_>>();
+        pair_indexes.sort();
+        let contains_two_pairs = match pair_indexes.first() {
+            Some(&&first) => pair_indexes.iter().any(|&&i| i - first > 1),
+            None => false,
+        };
+
+        ascending_pattern && valid_letters && contains_two_pairs
+    }
+}
+
+impl From<Password> for String {
+    fn from(value: Password) -> Self {
+        let Password(chars) = value;
+        chars.iter().collect()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type Input<'a> = &'a str;
+    type ParseError = Infallible;
+    type PartOne = Option<String>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        let mut password = Password::new(input);
+
+        password.next_password();
+        while !password.is_valid() {
+            password.next_password();
+        }
+
+        Some(password.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut password = Password::new(input);
+
+        for _ in 0..2 {
+            password.next_password();
+            while !password.is_valid() {
+                password.next_password();
+            }
+        }
+
+        Some(password.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 11).unwrap();
+        assert_eq!(Day11::solve_part_one(&input), Some("abcdffaa".to_string()));
+
+        assert_eq!(Day11::solve_part_one("ghijklmn"), Some("ghjaabcc".to_string()));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 11).unwrap();
+        assert_eq!(Day11::solve_part_two(&input), None);
+    }
+
+    #[test]
+    fn password_is_valid() {
+        let password = Password::new("abcdffaa");
+        assert!(password.is_valid());
+
+        let password = Password::new("ghjaabcc");
+        assert!(password.is_valid());
+
+        let password = Password::new("hijklmmn");
+        assert!(!password.is_valid());
+
+        let password = Password::new("abbceffg");
+        assert!(!password.is_valid());
+
+        let password = Password::new("abbcegjk");
+        assert!(!password.is_valid());
+    }
+}
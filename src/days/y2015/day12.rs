@@ -0,0 +1,73 @@
+use serde_json::Value;
+
+use crate::Solution;
+
+fn sum_of_numbers(value: &Value) -> i32 {
+    match value {
+        Value::Number(n) => n.as_i64().unwrap() as i32,
+        Value::Array(a) => a.iter().map(sum_of_numbers).sum(),
+        Value::Object(o) => o.values().map(sum_of_numbers).sum(),
+        _ => 0,
+    }
+}
+
+fn sum_of_numbers_minus_red(value: &Value) -> i32 {
+    match value {
+        Value::Number(n) => n.as_i64().unwrap() as i32,
+        Value::Array(a) => a.iter().map(sum_of_numbers_minus_red).sum(),
+        Value::Object(o) => {
+            let red = o.values().any(|value| match value {
+                Value::String(s) => s == "red",
+                _ => false,
+            });
+
+            if red {
+                return 0;
+            }
+
+            o.values().map(sum_of_numbers_minus_red).sum()
+        }
+        _ => 0,
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Value;
+    type ParseError = serde_json::Error;
+    type PartOne = Option<i32>;
+    type PartTwo = Option<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        serde_json::from_str(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(sum_of_numbers(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        Some(sum_of_numbers_minus_red(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file_part(2015, "examples", 12, 1).unwrap();
+        assert_eq!(Day12::solve_part_one(&input), Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file_part(2015, "examples", 12, 2).unwrap();
+        assert_eq!(Day12::solve_part_two(&input), Some(16));
+    }
+}
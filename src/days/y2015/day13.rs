@@ -0,0 +1,136 @@
+use crate::{
+    helpers::{
+        graph::AdjacencyMatrix,
+        scan::{parse_lines, Scan, ScanError},
+    },
+    scan, Solution,
+};
+
+enum Change {
+    Gain,
+    Lose,
+}
+
+impl Scan<'_> for Change {
+    const EXPECTED: &'static str = "\"gain\" or \"lose\"";
+
+    fn scan(s: &str) -> Option<Self> {
+        match s {
+            "gain" => Some(Self::Gain),
+            "lose" => Some(Self::Lose),
+            _ => None,
+        }
+    }
+}
+
+pub struct Preference<'a> {
+    person: &'a str,
+    neighbour: &'a str,
+    happiness: i32,
+}
+
+impl<'a> Preference<'a> {
+    fn parse(s: &'a str) -> Result<Self, ScanError> {
+        let (person, change, units, neighbour) = scan!(
+            "{} would {} {} happiness units by sitting next to {}.",
+            s => &str, Change, i32, &str
+        )?;
+        let happiness = match change {
+            Change::Gain => units,
+            Change::Lose => -units,
+        };
+        Ok(Self {
+            person,
+            neighbour,
+            happiness,
+        })
+    }
+}
+
+/// Returns the total change in happiness of the best seating around a circular table.
+fn optimal_happiness(preferences: &AdjacencyMatrix<i32>) -> i32 {
+    let people = preferences.len();
+    let happiness = |a: usize, b: usize| {
+        preferences.get(a, b).copied().unwrap_or_default()
+            + preferences.get(b, a).copied().unwrap_or_default()
+    };
+
+    // the table is round, so the first person can always sit first.
+    // best[seated][last] is the happiness of the best row of the `seated` bitmask that ends in `last`.
+    let mut best = vec![vec![None; people]; 1 << people];
+    if people > 0 {
+        best[1][0] = Some(0);
+    }
+
+    for seated in 1..best.len() {
+        for last in 0..people {
+            let Some(total) = best[seated][last] else {
+                continue;
+            };
+            for next in (0..people).filter(|next| seated & 1 << next == 0) {
+                let row = &mut best[seated | 1 << next][next];
+                let total = total + happiness(last, next);
+                *row = Some(row.map_or(total, |row: i32| row.max(total)));
+            }
+        }
+    }
+
+    best[best.len() - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(last, total)| Some(total.as_ref()? + happiness(last, 0)))
+        .max()
+        .unwrap_or_default()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Input<'a> = AdjacencyMatrix<i32>;
+    type ParseError = ScanError;
+    type PartOne = Option<i32>;
+    type PartTwo = Option<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        let preferences = parse_lines(input, Preference::parse)?;
+        let (_, preferences) = AdjacencyMatrix::directed(
+            preferences
+                .iter()
+                .map(|preference| (preference.person, preference.neighbour, preference.happiness)),
+        );
+        Ok(preferences)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        Some(optimal_happiness(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut preferences = input.clone();
+        // nobody minds sitting next to me, so I'm a node without any edges.
+        preferences.add_node();
+
+        Some(optimal_happiness(&preferences))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 13).unwrap();
+        assert_eq!(Day13::solve_part_one(&input), Some(330));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 13).unwrap();
+        assert_eq!(Day13::solve_part_two(&input), None);
+    }
+}
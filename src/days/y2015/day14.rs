@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use crate::{
+    helpers::scan::{parse_lines, ScanError},
+    scan, Solution,
+};
+
+#[derive(Debug, Clone)]
+enum State {
+    Flying,
+    Resting,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reindeer {
+    flying_speed: u32,
+    flying_time: u32,
+    resting_time: u32,
+    state: State,
+    distance: u32,
+    countdown: u32,
+    score: u32,
+}
+
+impl Reindeer {
+    fn step_in_simulation(&mut self) {
+        match self.state {
+            State::Flying => {
+                self.distance += self.flying_speed;
+                self.countdown -= 1;
+                if self.countdown == 0 {
+                    self.state = State::Resting;
+                    self.countdown = self.resting_time;
+                }
+            }
+            State::Resting => {
+                self.countdown -= 1;
+                if self.countdown == 0 {
+                    self.state = State::Flying;
+                    self.countdown = self.flying_time;
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, flying_speed, flying_time, resting_time) = scan!(
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+            s => &str, u32, u32, u32
+        )?;
+
+        Ok(Self {
+            flying_speed,
+            flying_time,
+            resting_time,
+            state: State::Flying,
+            distance: 0,
+            countdown: flying_time,
+            score: 0,
+        })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<Reindeer>;
+    type ParseError = ScanError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+        let mut reindeers = input.clone();
+
+        for _ in 0..2503 {
+            for reindeer in reindeers.iter_mut() {
+                reindeer.step_in_simulation();
+            }
+        }
+
+        reindeers.sort_by_key(|reindeer| reindeer.distance);
+
+        reindeers.last().map(|reindeer| reindeer.distance)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+        let mut reindeers = input.clone();
+
+        for _ in 0..2503 {
+            for reindeer in reindeers.iter_mut() {
+                reindeer.step_in_simulation();
+            }
+
+            reindeers.sort_by_key(|reindeer| reindeer.distance);
+
+            let winning_distance = reindeers.last().map(|reindeer| reindeer.distance).unwrap();
+
+            for reindeer in reindeers
+                .iter_mut()
+                .filter(|reindeer| reindeer.distance == winning_distance)
+            {
+                reindeer.score += 1;
+            }
+        }
+
+        reindeers.sort_by_key(|reindeer| reindeer.score);
+
+        reindeers.last().map(|reindeer| reindeer.score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2015, "examples", 14).unwrap();
+        assert_eq!(Day14::solve_part_one(&input), Some(2660));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2015, "examples", 14).unwrap();
+        assert_eq!(Day14::solve_part_two(&input), Some(1564));
+    }
+}
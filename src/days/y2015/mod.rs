@@ -0,0 +1,21 @@
+/*
+ * Solutions for 2015, see `src/days/mod.rs`.
+ * `cargo scaffold` adds new days to the `days!` invocation at the bottom of this file, ordered by day.
+ */
+
+days! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+}
@@ -0,0 +1,313 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+use crate::{aoc_client::get_puzzle_path, template, year_dir};
+
+#[derive(Debug)]
+pub enum ExtractError {
+    PuzzleNotFound(PathBuf),
+    IoError(PathBuf, io::Error),
+}
+
+impl Display for ExtractError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExtractError::PuzzleNotFound(path) => write!(
+                f,
+                "could not read \"{}\", download the puzzle first.",
+                path.display()
+            ),
+            ExtractError::IoError(path, e) => {
+                write!(f, "could not write \"{}\": {e}", path.display())
+            }
+        }
+    }
+}
+
+/// The examples found in one part of a puzzle description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartExamples {
+    /// Contents of every code block (`<pre><code>` on the website), in order.
+    pub blocks: Vec<String>,
+    /// The last emphasized inline code (`<code><em>` on the website), usually the example's answer.
+    pub answer: Option<String>,
+}
+
+/// Extracts examples from a downloaded puzzle description, one entry per released part.
+pub fn extract(markdown: &str) -> Vec<PartExamples> {
+    let mut parts = vec![PartExamples::default()];
+    let mut in_heading = false;
+    let mut code_block: Option<String> = None;
+    let mut emphasis = 0;
+
+    for event in Parser::new(markdown) {
+        let part = parts.last_mut().unwrap();
+        match event {
+            Event::Start(Tag::Heading { .. }) => in_heading = true,
+            Event::End(TagEnd::Heading(_)) => in_heading = false,
+            Event::Text(text) if in_heading && text.contains("Part Two") => {
+                parts.push(PartExamples::default());
+            }
+            Event::Start(Tag::CodeBlock(_)) => code_block = Some(String::new()),
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some(block) = code_block.take() {
+                    part.blocks
+                        .push(block.trim_end_matches('\n').to_string() + "\n");
+                }
+            }
+            Event::Text(text) => {
+                if let Some(block) = code_block.as_mut() {
+                    block.push_str(&text);
+                }
+            }
+            Event::Start(Tag::Emphasis | Tag::Strong) => emphasis += 1,
+            Event::End(TagEnd::Emphasis | TagEnd::Strong) => emphasis -= 1,
+            Event::Code(code) => {
+                let emphasized = code
+                    .strip_prefix('*')
+                    .and_then(|code| code.strip_suffix('*'))
+                    .filter(|code| !code.is_empty());
+
+                if let Some(answer) = emphasized {
+                    part.answer = Some(answer.to_string());
+                } else if emphasis > 0 {
+                    part.answer = Some(code.to_string());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    parts
+}
+
+/// Writes the examples of a downloaded puzzle to `src/<year>/examples` and fills the expected answers into the
+/// scaffolded tests of `src/days/y<year>/dayNN.rs`.
+///
+/// Example files that are not empty are only replaced with `overwrite`.
+pub fn write_examples(year: u16, day: u8, overwrite: bool) -> Result<(), ExtractError> {
+    let puzzle_path = get_puzzle_path(year, day);
+    let puzzle =
+        fs::read_to_string(&puzzle_path).map_err(|_| ExtractError::PuzzleNotFound(puzzle_path))?;
+
+    let parts = extract(&puzzle);
+    let examples_dir = year_dir(year).join("examples");
+
+    if parts[0].blocks.is_empty() {
+        println!("No example found for part 1.");
+    }
+    for (file_name, example) in example_files(day, &parts) {
+        write_example(examples_dir.join(file_name), example, overwrite)?;
+    }
+
+    let module_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/days")
+        .join(format!("y{year}"))
+        .join(format!("day{day:02}.rs"));
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        return Ok(());
+    };
+
+    for (i, part) in parts.iter().enumerate() {
+        let part_number = i as u8 + 1;
+        let Some(answer) = &part.answer else {
+            continue;
+        };
+
+        match fill_test_answer(&module, part_number, answer) {
+            Some(filled) => {
+                module = filled;
+                println!(
+                    "Expecting {answer} in the part {part_number} test of \"{}\"",
+                    module_path.display()
+                );
+            }
+            None => println!("Part {part_number} expects {answer} for its example."),
+        }
+    }
+
+    fs::write(&module_path, module).map_err(|e| ExtractError::IoError(module_path, e))
+}
+
+/// Names every code block of `parts` after the part it belongs to.
+///
+/// The first block of part one goes to `NN.txt`, the first block of part two to `NN-2.txt`, and every further
+/// block of a part to `NN-<part>b.txt`, `NN-<part>c.txt` and so on. Part two usually reuses the first example,
+/// so a block that repeats it is skipped.
+fn example_files(day: u8, parts: &[PartExamples]) -> Vec<(String, &str)> {
+    let part_one_example = parts.first().and_then(|part| part.blocks.first());
+    let mut files = vec![];
+
+    for (i, part) in parts.iter().enumerate() {
+        let blocks = part
+            .blocks
+            .iter()
+            .filter(|&block| i == 0 || Some(block) != part_one_example);
+
+        for (j, block) in blocks.enumerate() {
+            let file_name = match (i, j) {
+                (0, 0) => format!("{day:02}.txt"),
+                (_, 0) => format!("{day:02}-{}.txt", i + 1),
+                _ => format!("{day:02}-{}{}.txt", i + 1, (b'a' + j as u8) as char),
+            };
+            files.push((file_name, block.as_str()));
+        }
+    }
+
+    files
+}
+
+/// Writes an example file unless it already has contents.
+fn write_example(path: PathBuf, contents: &str, overwrite: bool) -> Result<(), ExtractError> {
+    let has_contents = fs::read_to_string(&path).is_ok_and(|existing| !existing.is_empty());
+    if has_contents && !overwrite {
+        println!(
+            "Skipped \"{}\", it is not empty. Use `--overwrite` to replace it.",
+            path.display()
+        );
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ExtractError::IoError(path.clone(), e))?;
+    }
+    fs::write(&path, contents).map_err(|e| ExtractError::IoError(path.clone(), e))?;
+    println!("Wrote example to \"{}\"", path.display());
+    Ok(())
+}
+
+/// Returns the title of a downloaded puzzle description, e.g. "Day 9: All in a Single Night".
+pub fn title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        line.trim_start_matches('\\')
+            .strip_prefix("--- ")?
+            .strip_suffix(" ---")
+            .map(|title| title.to_string())
+    })
+}
+
+/// Replaces the scaffolded assertion of a part's test, `None` or an unsolved `Err`, with `answer`.
+///
+/// Returns `None` if the test was already edited or `answer` is not a number.
+pub fn fill_test_answer(module: &str, part: u8, answer: &str) -> Option<String> {
+    answer.parse::<i64>().ok()?;
+
+    let solver = match part {
+        1 => "solve_part_one",
+        _ => "solve_part_two",
+    };
+    [
+        (template::UNSOLVED_OPTION, "Some"),
+        (template::UNSOLVED_RESULT, "Ok"),
+    ]
+    .into_iter()
+    .find_map(|(unsolved, solved)| {
+        let scaffolded = format!("::{solver}(&input), {unsolved});");
+        module.contains(&scaffolded).then(|| {
+            module.replacen(
+                &scaffolded,
+                &format!("::{solver}(&input), {solved}({answer}));"),
+                1,
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "\\--- Day 9: All in a Single Night ---
+----------
+
+For example, given the following distances:
+
+```
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141
+
+```
+
+The shortest of these is `London -> Dublin -> Belfast = 605`, and so the answer is `*605*` in this example.
+
+\\--- Part Two ---
+----------
+
+For example, given the distances above, the longest route would be *`982`*.
+";
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PUZZLE),
+            vec![
+                PartExamples {
+                    blocks: vec![
+                        "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141\n"
+                            .into()
+                    ],
+                    answer: Some("605".into()),
+                },
+                PartExamples {
+                    blocks: vec![],
+                    answer: Some("982".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example_files() {
+        let parts = [
+            PartExamples {
+                blocks: vec!["1\n".into(), "2\n".into()],
+                answer: None,
+            },
+            PartExamples {
+                blocks: vec!["1\n".into(), "3\n".into(), "4\n".into()],
+                answer: None,
+            },
+        ];
+        assert_eq!(
+            example_files(9, &parts),
+            vec![
+                ("09.txt".into(), "1\n"),
+                ("09-1b.txt".into(), "2\n"),
+                ("09-2.txt".into(), "3\n"),
+                ("09-2b.txt".into(), "4\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title(PUZZLE), Some("Day 9: All in a Single Night".into()));
+        assert_eq!(title("no heading"), None);
+    }
+
+    #[test]
+    fn test_fill_test_answer() {
+        let module = "assert_eq!(Day09::solve_part_one(&input), None);\nassert_eq!(Day09::solve_part_two(&input), None);\n";
+
+        let module = fill_test_answer(module, 2, "982").unwrap();
+        assert_eq!(
+            module,
+            "assert_eq!(Day09::solve_part_one(&input), None);\nassert_eq!(Day09::solve_part_two(&input), Some(982));\n"
+        );
+
+        assert_eq!(fill_test_answer(&module, 2, "982"), None);
+        assert_eq!(fill_test_answer(&module, 1, "abc"), None);
+
+        let module = "assert_eq!(Day09::solve_part_one(&input), Err(\"not solved yet\".into()));\n";
+        assert_eq!(
+            fill_test_answer(module, 1, "605"),
+            Some("assert_eq!(Day09::solve_part_one(&input), Ok(605));\n".into())
+        );
+    }
+}
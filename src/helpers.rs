@@ -2,3 +2,9 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod scan;
+pub mod search;
@@ -0,0 +1,274 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point or vector on a 2D plane, with `y` growing downwards like the rows of a puzzle input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// Returns the sum of the absolute differences of both coordinates, also for unsigned `T`.
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl Point<i64> {
+    /// Returns the point one step in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" is not a direction.", self.0)
+    }
+}
+
+/// One of the four cardinal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The cardinal directions, clockwise starting north.
+    pub const CARDINAL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// All eight directions, clockwise starting north.
+    pub const ALL: [Direction; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Returns the offset of one step in this direction, north is towards negative `y`.
+    pub fn offset(self) -> Point<i64> {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::NorthEast => Point::new(1, -1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(0, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::CARDINAL.contains(&self)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(index + eighths) % Self::ALL.len()]
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 45° counter-clockwise.
+    pub fn turn_left_diagonal(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turns 45° clockwise.
+    pub fn turn_right_diagonal(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Parses arrows (`^v<>`), compass points (`NSEW`) and moves (`UDLR`), ignoring case for letters.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            '^' | 'N' | 'U' => Ok(Self::North),
+            'V' | 'S' | 'D' => Ok(Self::South),
+            '>' | 'E' | 'R' => Ok(Self::East),
+            '<' | 'W' | 'L' => Ok(Self::West),
+            _ => Err(ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parses a single direction character or a diagonal compass point like `NE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, None) => Self::try_from(c).map_err(|_| ParseDirectionError(s.into())),
+            _ => match s.to_ascii_uppercase().as_str() {
+                "NE" => Ok(Self::NorthEast),
+                "SE" => Ok(Self::SouthEast),
+                "SW" => Ok(Self::SouthWest),
+                "NW" => Ok(Self::NorthWest),
+                _ => Err(ParseDirectionError(s.into())),
+            },
+        }
+    }
+}
+
+/// Parses a line of single character directions, e.g. `^>v<`, failing on the first unknown character.
+pub fn parse_directions(s: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+    s.chars().map(Direction::try_from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a * 2, Point::new(-6, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(Point::new(2_u32, 7).manhattan(Point::new(5, 1)), 9);
+        assert_eq!(
+            Point::default().step(Direction::NorthWest),
+            Point::new(-1, -1)
+        );
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::SouthEast.turn_right(), Direction::SouthWest);
+        assert_eq!(Direction::North.turn_left_diagonal(), Direction::NorthWest);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert!(Direction::NorthEast.is_diagonal());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_directions("^>v<"),
+            Ok(vec![
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West
+            ])
+        );
+        assert_eq!(
+            parse_directions("UDLRnsew")
+                .unwrap()
+                .into_iter()
+                .map(Direction::offset)
+                .fold(Point::default(), Add::add),
+            Point::new(0, 0)
+        );
+        assert_eq!("nw".parse(), Ok(Direction::NorthWest));
+        assert_eq!(
+            "x".parse::<Direction>(),
+            Err(ParseDirectionError("x".into()))
+        );
+        assert_eq!(parse_directions("^?"), Err(ParseDirectionError("?".into())));
+    }
+}
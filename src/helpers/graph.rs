@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+/// Maps names to dense ids `0..len`, in the order they were first seen.
+#[derive(Debug, Clone, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, usize>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of `name`, assigning the next free one if it was not seen before.
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.names.len() - 1
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// # Panics
+    ///
+    /// If `id` was not handed out by this interner.
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterates over `(id, name)` pairs in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.names.iter().copied().enumerate()
+    }
+}
+
+/// Weighted edges between nodes `0..len`, stored as a dense `len` x `len` matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix<W> {
+    len: usize,
+    weights: Vec<Option<W>>,
+}
+
+impl<W: Clone> AdjacencyMatrix<W> {
+    /// Creates a matrix of `len` nodes without any edges.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            weights: vec![None; len * len],
+        }
+    }
+
+    /// Builds a matrix from `(from, to, weight)` edges, returning the interner that maps the names to node ids.
+    pub fn directed<'a>(
+        edges: impl IntoIterator<Item = (&'a str, &'a str, W)>,
+    ) -> (Interner<'a>, Self) {
+        Self::from_edges(edges, false)
+    }
+
+    /// Like [`AdjacencyMatrix::directed`], but every edge also goes from `to` back to `from`.
+    pub fn undirected<'a>(
+        edges: impl IntoIterator<Item = (&'a str, &'a str, W)>,
+    ) -> (Interner<'a>, Self) {
+        Self::from_edges(edges, true)
+    }
+
+    fn from_edges<'a>(
+        edges: impl IntoIterator<Item = (&'a str, &'a str, W)>,
+        undirected: bool,
+    ) -> (Interner<'a>, Self) {
+        let mut interner = Interner::new();
+        let edges: Vec<(usize, usize, W)> = edges
+            .into_iter()
+            .map(|(from, to, weight)| (interner.intern(from), interner.intern(to), weight))
+            .collect();
+
+        let mut matrix = Self::new(interner.len());
+        for (from, to, weight) in edges {
+            if undirected {
+                matrix.set(to, from, weight.clone());
+            }
+            matrix.set(from, to, weight);
+        }
+        (interner, matrix)
+    }
+
+    /// Adds a node without any edges, returning its id.
+    pub fn add_node(&mut self) -> usize {
+        let len = self.len + 1;
+        let mut weights = vec![None; len * len];
+        for (from, row) in self.weights.chunks(self.len.max(1)).enumerate() {
+            weights[from * len..from * len + self.len].clone_from_slice(row);
+        }
+        *self = Self { len, weights };
+        len - 1
+    }
+}
+
+impl<W> AdjacencyMatrix<W> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the weight of the edge from `from` to `to`, if there is one.
+    ///
+    /// # Panics
+    ///
+    /// If either node is out of bounds.
+    pub fn get(&self, from: usize, to: usize) -> Option<&W> {
+        assert!(to < self.len, "node {to} is out of bounds");
+        self.weights[from * self.len + to].as_ref()
+    }
+
+    /// # Panics
+    ///
+    /// If either node is out of bounds.
+    pub fn set(&mut self, from: usize, to: usize, weight: W) {
+        assert!(to < self.len, "node {to} is out of bounds");
+        self.weights[from * self.len + to] = Some(weight);
+    }
+
+    /// Iterates over the nodes `from` has an edge to, with the weight of that edge.
+    pub fn neighbours(&self, from: usize) -> impl Iterator<Item = (usize, &W)> + '_ {
+        self.weights[from * self.len..(from + 1) * self.len]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, weight)| Some((to, weight.as_ref()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+        assert_eq!(interner.intern("London"), 0);
+        assert_eq!(interner.intern("Dublin"), 1);
+        assert_eq!(interner.intern("London"), 0);
+        assert_eq!(interner.get("Dublin"), Some(1));
+        assert_eq!(interner.get("Belfast"), None);
+        assert_eq!(interner.name(1), "Dublin");
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            vec![(0, "London"), (1, "Dublin")]
+        );
+    }
+
+    #[test]
+    fn test_adjacency_matrix() {
+        let edges = [("London", "Dublin", 464), ("London", "Belfast", 518)];
+        let (interner, matrix) = AdjacencyMatrix::undirected(edges);
+        let (london, belfast) = (
+            interner.get("London").unwrap(),
+            interner.get("Belfast").unwrap(),
+        );
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix.get(belfast, london), Some(&518));
+        assert_eq!(matrix.get(london, london), None);
+        assert_eq!(
+            matrix.neighbours(london).collect::<Vec<_>>(),
+            vec![(1, &464), (2, &518)]
+        );
+
+        let (_, mut matrix) = AdjacencyMatrix::directed(edges);
+        assert_eq!(matrix.get(belfast, london), None);
+        let node = matrix.add_node();
+        assert_eq!((node, matrix.len()), (3, 4));
+        assert_eq!(matrix.get(london, belfast), Some(&518));
+        assert_eq!(matrix.neighbours(node).count(), 0);
+    }
+}
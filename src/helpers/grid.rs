@@ -0,0 +1,383 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// Offsets of the orthogonal neighbours of a cell, `(dx, dy)` with `y` growing downwards.
+pub const NEIGHBOURS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours of a cell.
+pub const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// A line is longer or shorter than the first one.
+    RaggedLine(usize),
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the grid has no cells."),
+            GridError::RaggedLine(line) => {
+                write!(f, "line {line} does not have the width of the first line.")
+            }
+        }
+    }
+}
+
+/// Iterates all positions of the rectangle spanned by `from` and `to`, both inclusive, row by row.
+pub fn rect(from: (usize, usize), to: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    (from.1..=to.1).flat_map(move |y| (from.0..=to.0).map(move |x| (x, y)))
+}
+
+/// A dense grid of `width * height` cells, indexed by `(x, y)` with `(0, 0)` in the top left corner.
+///
+/// Cells live on the heap, so large grids don't need a bigger stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        Self {
+            width,
+            height,
+            cells: rect((0, 0), (width.max(1) - 1, height.max(1) - 1))
+                .take(width * height)
+                .map(&mut f)
+                .collect(),
+        }
+    }
+
+    /// Parses a block of text with one row per line, converting every character with `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(GridError::RaggedLine(i + 1));
+            }
+            cells.extend(line.chars().map(&mut f));
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height: cells.len() / width,
+                cells,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.1 * self.width + position.0])
+    }
+
+    /// Iterates all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        rect((0, 0), (self.width.max(1) - 1, self.height.max(1) - 1)).zip(&self.cells)
+    }
+
+    /// Iterates all cells row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates the rows of the rectangle spanned by `from` and `to`, both inclusive, as slices.
+    ///
+    /// Looping over the slices is a lot faster than indexing every position of [`rect`].
+    pub fn rect_rows_mut(
+        &mut self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> impl Iterator<Item = &mut [T]> {
+        assert!(
+            from.0 <= to.0 && from.1 <= to.1 && self.contains(to),
+            "{from:?} to {to:?} is not a rectangle within the grid"
+        );
+        self.cells
+            .chunks_mut(self.width)
+            .skip(from.1)
+            .take(to.1 - from.1 + 1)
+            .map(move |row| &mut row[from.0..=to.0])
+    }
+
+    /// Iterates the positions of the orthogonal neighbours of `position` that lie within the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_all(position, &NEIGHBOURS_4)
+    }
+
+    /// Iterates the positions of the orthogonal and diagonal neighbours of `position` that lie within the grid.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_all(position, &NEIGHBOURS_8)
+    }
+
+    fn offset_all<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let position = (
+                usize::try_from(x as i64 + dx).ok()?,
+                usize::try_from(y as i64 + dy).ok()?,
+            );
+            self.contains(position).then_some(position)
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        assert!(
+            self.contains(position),
+            "{position:?} is outside of the grid"
+        );
+        &self.cells[position.1 * self.width + position.0]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            self.contains(position),
+            "{position:?} is outside of the grid"
+        );
+        &mut self.cells[position.1 * self.width + position.0]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |c| c)
+    }
+}
+
+/// A sparse grid for unbounded, possibly negative coordinates, only storing the cells that were set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+}
+
+impl<T> Default for HashGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> HashGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a block of text with one row per line, keeping the cells for which `f` returns a value.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Self {
+        let cells = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| ((x as i64, y as i64), c))
+            })
+            .filter_map(|(position, c)| Some((position, f(c)?)))
+            .collect();
+        Self { cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: (i64, i64)) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: (i64, i64)) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: (i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn insert(&mut self, position: (i64, i64), value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: (i64, i64)) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    pub fn entry(&mut self, position: (i64, i64)) -> Entry<'_, (i64, i64), T> {
+        self.cells.entry(position)
+    }
+
+    /// Iterates all set cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells
+            .iter()
+            .map(|(&position, value)| (position, value))
+    }
+
+    /// Returns the top left and bottom right corner of the smallest rectangle containing all set cells.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let mut positions = self.cells.keys();
+        let &first = positions.next()?;
+        Some(positions.fold(
+            (first, first),
+            |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            },
+        ))
+    }
+
+    /// Iterates the positions of the orthogonal neighbours of `position`, whether they are set or not.
+    pub fn neighbours4(position: (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+        NEIGHBOURS_4
+            .iter()
+            .map(move |&(dx, dy)| (position.0 + dx, position.1 + dy))
+    }
+
+    /// Iterates the positions of the orthogonal and diagonal neighbours of `position`, whether they are set or not.
+    pub fn neighbours8(position: (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+        NEIGHBOURS_8
+            .iter()
+            .map(move |&(dx, dy)| (position.0 + dx, position.1 + dy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#..\n.#.\n..#\n.##";
+
+    #[test]
+    fn test_grid_parse() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.rows().nth(3), Some(&['.', '#', '#'][..]));
+        assert_eq!(grid.iter().filter(|(_, &c)| c == '#').count(), 5);
+
+        assert_eq!(
+            "#..\n.#".parse::<Grid<char>>(),
+            Err(GridError::RaggedLine(2))
+        );
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = Grid::new(3, 3, 0_u8);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rect() {
+        let mut grid = Grid::from_fn(4, 3, |(x, y)| x + y);
+        assert_eq!(grid[(3, 2)], 5);
+
+        for position in rect((1, 1), (2, 2)) {
+            grid[position] = 0;
+        }
+        assert_eq!(grid.values().filter(|&&value| value == 0).count(), 5);
+
+        for row in grid.rect_rows_mut((2, 0), (3, 1)) {
+            row.fill(9);
+        }
+        assert_eq!(grid.values().filter(|&&value| value == 9).count(), 4);
+        assert_eq!(grid[(1, 1)], 0);
+    }
+
+    #[test]
+    fn test_hash_grid() {
+        let mut grid = HashGrid::parse(EXAMPLE, |c| (c == '#').then_some(()));
+        assert_eq!(grid.len(), 5);
+        assert!(grid.contains((2, 3)));
+
+        grid.insert((-4, 1), ());
+        assert_eq!(grid.bounds(), Some(((-4, 0), (2, 3))));
+        assert_eq!(
+            HashGrid::<()>::neighbours4((0, 0))
+                .filter(|&position| grid.contains(position))
+                .count(),
+            0
+        );
+    }
+}
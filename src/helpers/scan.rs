@@ -0,0 +1,242 @@
+use std::fmt::Display;
+
+/// Parses `line` against a pattern with `{}` placeholders into a tuple, see [`scan`].
+///
+/// The types of the fields can be given after `=>`, otherwise they are inferred from the usage of the result.
+///
+/// ```
+/// use advent_of_code::scan;
+///
+/// let (from, to, distance) = scan!("{} to {} = {}", "London to Dublin = 464" => &str, &str, u32).unwrap();
+/// assert_eq!((from, to, distance), ("London", "Dublin", 464));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr, $line:expr => $($ty:ty),+ $(,)?) => {
+        $crate::helpers::scan::scan::<($($ty,)+)>($pattern, $line)
+    };
+    ($pattern:expr, $line:expr) => {
+        $crate::helpers::scan::scan($pattern, $line)
+    };
+}
+
+/// Where and why a line did not match its pattern.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScanError {
+    /// 1-based line number, set by [`parse_lines`].
+    pub line: Option<usize>,
+    /// 1-based column of the first character that did not match.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+        write!(
+            f,
+            "column {}: expected {}, found \"{}\"",
+            self.column, self.expected, self.found
+        )
+    }
+}
+
+/// A value that can be read from the text of a placeholder.
+pub trait Scan<'a>: Sized {
+    /// Describes the value in errors, e.g. `an unsigned integer`.
+    const EXPECTED: &'static str;
+
+    fn scan(s: &'a str) -> Option<Self>;
+}
+
+impl<'a> Scan<'a> for &'a str {
+    const EXPECTED: &'static str = "some text";
+
+    fn scan(s: &'a str) -> Option<Self> {
+        Some(s)
+    }
+}
+
+macro_rules! impl_scan {
+    ($expected:literal => $($ty:ty),+) => {
+        $(
+            impl Scan<'_> for $ty {
+                const EXPECTED: &'static str = $expected;
+
+                fn scan(s: &str) -> Option<Self> {
+                    s.parse().ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_scan!("an unsigned integer" => u8, u16, u32, u64, u128, usize);
+impl_scan!("an integer" => i8, i16, i32, i64, i128, isize);
+impl_scan!("a number" => f32, f64);
+impl_scan!("a character" => char);
+impl_scan!("\"true\" or \"false\"" => bool);
+impl_scan!("some text" => String);
+
+/// A tuple of values read from the placeholders of a pattern.
+pub trait FromFields<'a>: Sized {
+    const LEN: usize;
+
+    /// Converts the text of each placeholder, with the 1-based column it starts at.
+    fn from_fields(fields: &[(usize, &'a str)]) -> Result<Self, ScanError>;
+}
+
+fn field<'a, T: Scan<'a>>((column, s): (usize, &'a str)) -> Result<T, ScanError> {
+    T::scan(s).ok_or_else(|| ScanError {
+        line: None,
+        column,
+        expected: T::EXPECTED.into(),
+        found: s.into(),
+    })
+}
+
+macro_rules! impl_from_fields {
+    ($len:literal => $($ty:ident $i:tt),+) => {
+        impl<'a, $($ty: Scan<'a>),+> FromFields<'a> for ($($ty,)+) {
+            const LEN: usize = $len;
+
+            fn from_fields(fields: &[(usize, &'a str)]) -> Result<Self, ScanError> {
+                Ok(($(field::<$ty>(fields[$i])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1 => A 0);
+impl_from_fields!(2 => A 0, B 1);
+impl_from_fields!(3 => A 0, B 1, C 2);
+impl_from_fields!(4 => A 0, B 1, C 2, D 3);
+impl_from_fields!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Parses `line` against `pattern`, returning the values of its `{}` placeholders as a tuple.
+///
+/// Text between placeholders has to match exactly. A placeholder extends up to the next occurrence of the text
+/// following it, or to the end of the line for a placeholder at the end of the pattern.
+///
+/// # Panics
+///
+/// If the pattern has two placeholders without text between them or its placeholders don't match the tuple size.
+pub fn scan<'a, T: FromFields<'a>>(pattern: &str, line: &'a str) -> Result<T, ScanError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        T::LEN,
+        "pattern \"{pattern}\" does not have a placeholder for each of the {} values",
+        T::LEN
+    );
+
+    let column = |rest: &str| line[..line.len() - rest.len()].chars().count() + 1;
+    let mismatch = |rest: &str, expected: String| ScanError {
+        line: None,
+        column: column(rest),
+        expected,
+        found: rest.into(),
+    };
+
+    let mut rest = line
+        .strip_prefix(literals[0])
+        .ok_or_else(|| mismatch(line, format!("\"{}\"", literals[0])))?;
+    let mut fields = Vec::with_capacity(T::LEN);
+
+    for (i, &literal) in literals.iter().enumerate().skip(1) {
+        let is_last = i == literals.len() - 1;
+        let end = match literal {
+            "" if is_last => rest.len(),
+            "" => panic!("pattern \"{pattern}\" has two placeholders without text between them"),
+            literal => rest
+                .find(literal)
+                .ok_or_else(|| mismatch(rest, format!("\"{literal}\"")))?,
+        };
+
+        fields.push((column(rest), &rest[..end]));
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(mismatch(rest, "the end of the line".into()));
+    }
+
+    T::from_fields(&fields)
+}
+
+/// Parses every line of `input` with `f`, adding the line number to the first error.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ScanError>,
+) -> Result<Vec<T>, ScanError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            f(line).map_err(|e| ScanError {
+                line: Some(i + 1),
+                ..e
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan!("{} to {} = {}", "London to Dublin = 464" => &str, &str, u32),
+            Ok(("London", "Dublin", 464))
+        );
+        assert_eq!(scan("{}x{}x{}", "2x3x4"), Ok((2_u8, 3_u8, 4_u8)));
+        assert_eq!(scan("#{} @ {},{}", "#1 @ -3,5"), Ok((1, -3, 5)));
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        assert_eq!(
+            scan!("{} to {} = {}", "London to Dublin = far" => &str, &str, u32),
+            Err(ScanError {
+                line: None,
+                column: 20,
+                expected: "an unsigned integer".into(),
+                found: "far".into(),
+            })
+        );
+        assert_eq!(
+            scan!("{} to {} = {}", "London -> Dublin = 464" => &str, &str, u32)
+                .unwrap_err()
+                .to_string(),
+            "column 1: expected \" to \", found \"London -> Dublin = 464\""
+        );
+        assert_eq!(
+            scan!("{}x{}", "2x3x4" => u32, u32).unwrap_err().expected,
+            "an unsigned integer"
+        );
+        assert_eq!(
+            scan!("size {}.", "size 3. " => u32)
+                .unwrap_err()
+                .to_string(),
+            "column 8: expected the end of the line, found \" \""
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line| scan!("{}x{}", line => u32, u32);
+        assert_eq!(parse_lines("1x2\n3x4", parse), Ok(vec![(1, 2), (3, 4)]));
+        assert_eq!(
+            parse_lines("1x2\n3y4", parse).unwrap_err().to_string(),
+            "line 2, column 1: expected \"x\", found \"3y4\""
+        );
+    }
+}
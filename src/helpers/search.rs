@@ -0,0 +1,410 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Whether a search remembers the states it has reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visited {
+    /// Skips states that were already reached at the same or a lower cost.
+    Track,
+    /// Expands every state it reaches, for state spaces without repeats where hashing would only cost time.
+    /// A search over a graph with cycles will not finish this way.
+    Ignore,
+}
+
+/// A path from the start to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// The outcome of a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<N, C> {
+    /// The best path found, `None` if no goal is reachable.
+    pub path: Option<Path<N, C>>,
+    /// How many nodes had their successors generated, to compare heuristics and pruning.
+    pub expanded: usize,
+}
+
+/// Every node a search reached, with the index of the node it was reached from.
+struct Arena<N> {
+    nodes: Vec<(N, Option<usize>)>,
+}
+
+impl<N: Clone> Arena<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![(start, None)],
+        }
+    }
+
+    fn push(&mut self, node: N, parent: usize) -> usize {
+        self.nodes.push((node, Some(parent)));
+        self.nodes.len() - 1
+    }
+
+    fn node(&self, index: usize) -> &N {
+        &self.nodes[index].0
+    }
+
+    fn path<C>(&self, index: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![];
+        let mut current = Some(index);
+        while let Some(index) = current {
+            nodes.push(self.nodes[index].0.clone());
+            current = self.nodes[index].1;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Lowest costs a node was reached at, if they are tracked.
+struct Seen<N, C>(Option<HashMap<N, C>>);
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Seen<N, C> {
+    fn new(visited: Visited, start: &N, cost: C) -> Self {
+        Self(match visited {
+            Visited::Track => Some(HashMap::from([(start.clone(), cost)])),
+            Visited::Ignore => None,
+        })
+    }
+
+    /// Records `node` at `cost`, returning whether it was not reached that cheaply before.
+    fn improve(&mut self, node: &N, cost: C) -> bool {
+        let Some(seen) = &mut self.0 else {
+            return true;
+        };
+        match seen.entry(node.clone()) {
+            Entry::Occupied(mut entry) if cost < *entry.get() => {
+                entry.insert(cost);
+                true
+            }
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(cost);
+                true
+            }
+        }
+    }
+
+    /// Whether a cheaper way to `node` was found after it was queued at `cost`.
+    fn is_stale(&self, node: &N, cost: C) -> bool {
+        self.0
+            .as_ref()
+            .and_then(|seen| seen.get(node))
+            .is_some_and(|&best| best < cost)
+    }
+}
+
+/// Finds the path with the fewest steps from `start` to a node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    visited: Visited,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = match visited {
+        Visited::Track => Some(HashSet::from([start.clone()])),
+        Visited::Ignore => None,
+    };
+    let mut arena = Arena::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    let mut expanded = 0;
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if is_goal(arena.node(index)) {
+            return Search {
+                path: Some(arena.path(index, steps)),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for next in successors(arena.node(index)) {
+            if seen.as_mut().is_some_and(|seen| !seen.insert(next.clone())) {
+                continue;
+            }
+            queue.push_back((arena.push(next, index), steps + 1));
+        }
+    }
+
+    Search {
+        path: None,
+        expanded,
+    }
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`, `successors` yields each next node with
+/// the cost of the step to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+    visited: Visited,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal, visited)
+}
+
+/// Like [`dijkstra`], but expands the nodes with the lowest cost plus `heuristic` first.
+///
+/// The path is only guaranteed to be the cheapest if `heuristic` never overestimates the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    visited: Visited,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut seen = Seen::new(visited, &start, C::default());
+    // on equal estimates the node that got further is expanded first, it is closer to a goal.
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), Reverse(C::default()), 0))]);
+    let mut arena = Arena::new(start);
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, Reverse(cost), index))) = queue.pop() {
+        if seen.is_stale(arena.node(index), cost) {
+            continue;
+        }
+        if is_goal(arena.node(index)) {
+            return Search {
+                path: Some(arena.path(index, cost)),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for (next, step) in successors(arena.node(index)) {
+            let cost = cost + step;
+            if !seen.improve(&next, cost) {
+                continue;
+            }
+            let estimate = cost + heuristic(&next);
+            queue.push(Reverse((estimate, Reverse(cost), arena.push(next, index))));
+        }
+    }
+
+    Search {
+        path: None,
+        expanded,
+    }
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal` depth first, for state spaces too large
+/// to keep a queue of.
+///
+/// Only the nodes on the current path are kept, unless [`Visited::Track`] also remembers every node reached. Once a
+/// path is found, branches whose cost plus `lower_bound` can't beat it are pruned. `lower_bound` must never
+/// overestimate the remaining cost, `|_| 0` prunes only branches that are already too expensive.
+pub fn dfs<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut lower_bound: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    visited: Visited,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut seen = Seen::new(visited, &start, C::default());
+    // the nodes from the start to the current one, each with its cost and the successors not tried yet.
+    let mut path: Vec<N> = vec![];
+    let mut frames: Vec<(C, I::IntoIter)> = vec![];
+    let mut next = Some((start, C::default()));
+    let mut best: Option<Path<N, C>> = None;
+    let mut expanded = 0;
+
+    loop {
+        if let Some((node, cost)) = next.take() {
+            let pruned = best
+                .as_ref()
+                .is_some_and(|best| cost + lower_bound(&node) >= best.cost);
+            if !pruned && is_goal(&node) {
+                let mut nodes = path.clone();
+                nodes.push(node);
+                best = Some(Path { cost, nodes });
+            } else if !pruned {
+                expanded += 1;
+                frames.push((cost, successors(&node).into_iter()));
+                path.push(node);
+            }
+        }
+
+        let Some((cost, successors)) = frames.last_mut() else {
+            break;
+        };
+        match successors.next() {
+            Some((node, step)) => {
+                let cost = *cost + step;
+                if seen.improve(&node, cost) {
+                    next = Some((node, cost));
+                }
+            }
+            None => {
+                frames.pop();
+                path.pop();
+            }
+        }
+    }
+
+    Search {
+        path: best,
+        expanded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An undirected graph where the cheapest way from `a` to `e` is not the one with the fewest steps.
+    const EDGES: [(char, char, u32); 9] = [
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'c', 10),
+        ('b', 'd', 15),
+        ('c', 'd', 11),
+        ('c', 'f', 2),
+        ('d', 'e', 6),
+        ('e', 'f', 9),
+    ];
+
+    fn neighbours(node: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter_map(|&(a, b, cost)| match *node {
+                n if n == a => Some((b, cost)),
+                n if n == b => Some((a, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn path<C>(cost: C, nodes: &str) -> Option<Path<char, C>> {
+        Some(Path {
+            cost,
+            nodes: nodes.chars().collect(),
+        })
+    }
+
+    #[test]
+    fn test_bfs() {
+        let steps = |&n: &u32| [n + 1, n * 2];
+        let tracked = bfs(1, steps, |&n| n == 10, Visited::Track);
+        assert_eq!(tracked.path.unwrap().nodes, vec![1, 2, 4, 5, 10]);
+
+        let untracked = bfs(1, steps, |&n| n == 10, Visited::Ignore);
+        assert_eq!(untracked.path.unwrap().cost, 4);
+        assert!(untracked.expanded > tracked.expanded);
+
+        let unweighted = |node: &char| neighbours(node).into_iter().map(|(next, _)| next);
+        assert_eq!(
+            bfs('a', unweighted, |&n| n == 'e', Visited::Track).path,
+            path(2, "afe")
+        );
+        assert_eq!(
+            bfs('a', unweighted, |&n| n == 'z', Visited::Track).path,
+            None
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra('a', neighbours, |&n| n == 'e', Visited::Track).path,
+            path(20, "acfe")
+        );
+        assert_eq!(
+            dijkstra('a', neighbours, |&n| n == 'a', Visited::Track),
+            Search {
+                path: path(0, "a"),
+                expanded: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // a 5x5 grid with a wall across the middle row, except for its rightmost cell.
+        let open =
+            |(x, y): (i32, i32)| (0..5).contains(&x) && (0..5).contains(&y) && (y != 2 || x == 4);
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&next| open(next))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal = (0, 4);
+        let manhattan = |&(x, y): &(i32, i32)| (x - goal.0).abs() + (y - goal.1).abs();
+
+        let informed = astar(
+            (0, 0),
+            successors,
+            manhattan,
+            |&n| n == goal,
+            Visited::Track,
+        );
+        let uninformed = dijkstra((0, 0), successors, |&n| n == goal, Visited::Track);
+        assert_eq!(informed.path.as_ref().unwrap().cost, 12);
+        assert_eq!(
+            informed.path.as_ref().unwrap().nodes[6],
+            (4, 2),
+            "the path goes through the gap"
+        );
+        assert_eq!(uninformed.path.unwrap().cost, 12);
+        assert!(informed.expanded < uninformed.expanded);
+    }
+
+    #[test]
+    fn test_dfs() {
+        assert_eq!(
+            dfs('a', neighbours, |_| 0, |&n| n == 'e', Visited::Track).path,
+            path(20, "acfe")
+        );
+
+        // the fewest containers that hold exactly 25 liters, every state is reached once.
+        let containers = [20, 15, 10, 5, 5];
+        let successors = |&(next, left): &(usize, u32)| {
+            (next..containers.len())
+                .filter(|&i| containers[i] <= left)
+                .map(|i| ((i + 1, left - containers[i]), 1))
+                .collect::<Vec<_>>()
+        };
+        let is_goal = |&(_, left): &(usize, u32)| left == 0;
+
+        let unbounded = dfs((0, 25), successors, |_| 0, is_goal, Visited::Ignore);
+        let bounded = dfs(
+            (0, 25),
+            successors,
+            |&(_, left)| u32::from(left > 0),
+            is_goal,
+            Visited::Ignore,
+        );
+        assert_eq!(unbounded.path.unwrap().cost, 2);
+        assert_eq!(bounded.path.unwrap().cost, 2);
+        assert!(bounded.expanded < unbounded.expanded);
+    }
+}
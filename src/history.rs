@@ -0,0 +1,336 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config, PartResult};
+
+const HISTORY_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/aoc-history.jsonl");
+
+const PROFILE: &str = if cfg!(debug_assertions) {
+    "debug"
+} else {
+    "release"
+};
+
+/// The time a single part took in a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed_ns: u64,
+}
+
+/// The timings of one `cargo all` run, one per line in `target/aoc-history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// Unix timestamp of the run.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    /// Name given to the run with `cargo timings --tag`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Build profile of the run, `debug` or `release`. Missing in runs recorded before it was tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Whether the timings are medians of `--bench` rather than single samples.
+    #[serde(default)]
+    pub bench: bool,
+    /// The days selected with `--days`, `None` if all days ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<u8>>,
+    /// The part selected with `--part`, `None` if both parts ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<u8>,
+    pub parts: Vec<PartTiming>,
+}
+
+impl Run {
+    /// Collects the timings of all solved parts of a run of the selected `days` and `part`.
+    pub fn new(results: &[PartResult], days: Option<Vec<u8>>, part: Option<u8>) -> Self {
+        let parts = results
+            .iter()
+            .filter(|result| result.answer.is_some())
+            .map(|result| PartTiming {
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                elapsed_ns: result.elapsed.as_nanos() as u64,
+            })
+            .collect();
+
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            commit: git_commit(),
+            tag: None,
+            profile: Some(PROFILE.into()),
+            bench: config().bench.is_some(),
+            days,
+            part,
+            parts,
+        }
+    }
+
+    /// Whether both runs were made the same way, timings of other builds, modes or selections are not comparable.
+    pub fn is_comparable(&self, other: &Run) -> bool {
+        self.profile.is_some()
+            && self.profile == other.profile
+            && self.bench == other.bench
+            && self.days == other.days
+            && self.part == other.part
+    }
+
+    /// Describes how the run was made, e.g. `release, bench, days 3-9`.
+    pub fn setup(&self) -> String {
+        let mut setup = vec![self
+            .profile
+            .as_deref()
+            .unwrap_or("unknown profile")
+            .to_string()];
+        if self.bench {
+            setup.push("bench".into());
+        }
+        if let Some(days) = &self.days {
+            let days: Vec<String> = days.iter().map(u8::to_string).collect();
+            setup.push(format!("days {}", days.join(",")));
+        }
+        if let Some(part) = self.part {
+            setup.push(format!("part {part}"));
+        }
+        setup.join(", ")
+    }
+
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.parts.iter().map(|part| part.elapsed_ns).sum())
+    }
+
+    fn elapsed(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|timing| timing.year == year && timing.day == day && timing.part == part)
+            .map(|timing| Duration::from_nanos(timing.elapsed_ns))
+    }
+}
+
+/// How the timing of a part changed between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub latest: Duration,
+    /// Relative change in percent, positive if the part got slower.
+    pub change: f64,
+    /// Whether the part got slower by more than the threshold.
+    pub regressed: bool,
+}
+
+/// Compares all parts timed in both runs, flagging those that got more than `threshold` percent slower.
+pub fn compare(baseline: &Run, latest: &Run, threshold: f64) -> Vec<Delta> {
+    latest
+        .parts
+        .iter()
+        .filter_map(|timing| {
+            let baseline = baseline.elapsed(timing.year, timing.day, timing.part)?;
+            let latest = Duration::from_nanos(timing.elapsed_ns);
+            let change = if baseline.is_zero() {
+                0_f64
+            } else {
+                (latest.as_secs_f64() / baseline.as_secs_f64() - 1_f64) * 100_f64
+            };
+            Some(Delta {
+                year: timing.year,
+                day: timing.day,
+                part: timing.part,
+                baseline,
+                latest,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect()
+}
+
+/// Every recorded run, oldest first.
+#[derive(Debug, Default)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(HISTORY_PATH) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, io::Error> {
+        let runs = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { runs })
+    }
+
+    /// Appends a run to the history file.
+    pub fn record(run: &Run) -> Result<(), io::Error> {
+        if let Some(dir) = std::path::Path::new(HISTORY_PATH).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_PATH)?;
+        writeln!(file, "{}", serde_json::to_string(run)?)
+    }
+
+    /// Rewrites the history file, e.g. after tagging a run.
+    pub fn save(&self) -> Result<(), io::Error> {
+        let mut contents = String::new();
+        for run in &self.runs {
+            contents.push_str(&serde_json::to_string(run)?);
+            contents.push('\n');
+        }
+        fs::write(HISTORY_PATH, contents)
+    }
+
+    /// Finds the latest run tagged with `name`, or else the latest run of a commit starting with `name`.
+    pub fn find(&self, name: &str) -> Option<&Run> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| run.tag.as_deref() == Some(name))
+            .or_else(|| {
+                self.runs.iter().rev().find(|run| {
+                    run.commit
+                        .as_deref()
+                        .is_some_and(|commit| commit.starts_with(name))
+                })
+            })
+    }
+}
+
+/// Returns the short hash of `HEAD`, or `None` outside of a git repository.
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(commit: &str, tag: Option<&str>, parts: &[(u8, u8, u64)]) -> Run {
+        Run {
+            timestamp: 100,
+            commit: Some(commit.into()),
+            tag: tag.map(Into::into),
+            profile: Some("release".into()),
+            bench: false,
+            days: None,
+            part: None,
+            parts: parts
+                .iter()
+                .map(|&(day, part, elapsed_ns)| PartTiming {
+                    year: 2015,
+                    day,
+                    part,
+                    elapsed_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = run("abc1234", None, &[(1, 1, 1000), (1, 2, 1000), (2, 1, 500)]);
+        let latest = run("def5678", None, &[(1, 1, 1200), (1, 2, 900), (3, 1, 10)]);
+
+        let deltas = compare(&baseline, &latest, 10_f64);
+        assert_eq!(deltas.len(), 2);
+        assert!(deltas[0].regressed);
+        assert!((deltas[0].change - 20_f64).abs() < 1.0e-6);
+        assert!(!deltas[1].regressed);
+        assert!((deltas[1].change + 10_f64).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_find() {
+        let history = History::parse(
+            &[
+                run("abc1234", Some("before-grid"), &[]),
+                run("abc1234", None, &[]),
+                run("def5678", None, &[]),
+            ]
+            .iter()
+            .map(|run| serde_json::to_string(run).unwrap() + "\n")
+            .collect::<String>(),
+        )
+        .unwrap();
+
+        assert_eq!(history.find("before-grid"), Some(&history.runs[0]));
+        assert_eq!(history.find("abc"), Some(&history.runs[1]));
+        assert_eq!(history.find("123"), None);
+    }
+
+    #[test]
+    fn test_is_comparable() {
+        let full = run("abc1234", None, &[]);
+        let debug = Run {
+            profile: Some("debug".into()),
+            ..full.clone()
+        };
+        let bench = Run {
+            bench: true,
+            ..full.clone()
+        };
+        let partial = Run {
+            days: Some(vec![3, 4]),
+            part: Some(1),
+            ..full.clone()
+        };
+        let legacy: Run =
+            serde_json::from_str(r#"{"timestamp":1,"commit":"0000000","parts":[]}"#).unwrap();
+
+        assert!(full.is_comparable(&run("def5678", None, &[])));
+        assert!(!full.is_comparable(&debug));
+        assert!(!full.is_comparable(&bench));
+        assert!(!full.is_comparable(&partial));
+        assert!(!legacy.is_comparable(&legacy));
+        assert_eq!(partial.setup(), "release, days 3,4, part 1");
+    }
+}
@@ -0,0 +1,179 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use crate::config;
+
+/// Where a day binary reads its puzzle input from, set with `--input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `--input <path>`
+    Path(PathBuf),
+    /// `--input -`
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expected a path or \"-\" for stdin".into()),
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::Path(path.into())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadFileError {
+    NotFound(PathBuf),
+    IoError(PathBuf, io::Error),
+    StdinError(io::Error),
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadFileError::NotFound(path) => {
+                write!(f, "could not find \"{}\".", path.display())
+            }
+            ReadFileError::IoError(path, e) => {
+                write!(f, "could not read \"{}\": {e}", path.display())
+            }
+            ReadFileError::StdinError(e) => write!(f, "could not read stdin: {e}"),
+        }
+    }
+}
+
+/// Returns `src/<year>` of this repository, which holds the inputs, examples, puzzles and answers of a year.
+pub fn year_dir(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(year.to_string())
+}
+
+/// Reads `src/<year>/<folder>/<day>.txt` of this repository, independent of the working directory.
+pub fn read_file(year: u16, folder: &str, day: u8) -> Result<String, ReadFileError> {
+    read_path(&file_path(year, folder, day))
+}
+
+/// Reads `src/<year>/<folder>/<day>-<part>.txt`, falling back to `<day>.txt` if the part has no file of its own.
+///
+/// This lets a part whose example differs from the first one keep it in e.g. `examples/05-2.txt`.
+pub fn read_file_part(year: u16, folder: &str, day: u8, part: u8) -> Result<String, ReadFileError> {
+    match read_path(&part_file_path(year, folder, day, part)) {
+        Err(ReadFileError::NotFound(_)) => read_file(year, folder, day),
+        result => result,
+    }
+}
+
+/// Reads the puzzle input for `day` of `year`.
+///
+/// `--input` takes precedence over `AOC_INPUT_DIR`, which takes precedence over `src/<year>/inputs`.
+pub fn read_input(year: u16, day: u8) -> Result<String, ReadFileError> {
+    match &config().input {
+        Some(InputSource::Stdin) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(ReadFileError::StdinError)?;
+            Ok(input)
+        }
+        Some(InputSource::Path(path)) => read_path(path),
+        None => read_path(&input_path(year, day, config().input_dir.as_deref())),
+    }
+}
+
+/// Reads the puzzle input for `day` of `year`, exiting with an error message if it can't be read.
+pub fn read_input_or_exit(year: u16, day: u8) -> String {
+    match read_input(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input for day {day}, {year}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{day:02}.txt"))
+}
+
+fn part_file_path(year: u16, folder: &str, day: u8, part: u8) -> PathBuf {
+    year_dir(year)
+        .join(folder)
+        .join(format!("{day:02}-{part}.txt"))
+}
+
+fn input_path(year: u16, day: u8, input_dir: Option<&Path>) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(format!("{day:02}.txt")),
+        None => file_path(year, "inputs", day),
+    }
+}
+
+fn read_path(path: &Path) -> Result<String, ReadFileError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => ReadFileError::NotFound(path.to_path_buf()),
+        _ => ReadFileError::IoError(path.to_path_buf(), e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(2015, 3, Some(Path::new("/tmp/inputs"))),
+            PathBuf::from("/tmp/inputs/03.txt")
+        );
+        assert!(input_path(2015, 3, None).ends_with("src/2015/inputs/03.txt"));
+    }
+
+    #[test]
+    fn test_read_file() {
+        assert!(read_file(2015, "examples", 1).is_ok());
+        assert!(matches!(
+            read_file(2015, "examples", 99),
+            Err(ReadFileError::NotFound(path)) if path.ends_with("src/2015/examples/99.txt")
+        ));
+    }
+
+    #[test]
+    fn test_read_file_part() {
+        assert!(part_file_path(2015, "examples", 5, 2).ends_with("src/2015/examples/05-2.txt"));
+        assert_eq!(
+            read_file_part(2015, "examples", 5, 1).unwrap(),
+            read_file(2015, "examples", 5).unwrap()
+        );
+        assert_ne!(
+            read_file_part(2015, "examples", 5, 2).unwrap(),
+            read_file(2015, "examples", 5).unwrap()
+        );
+        assert!(matches!(
+            read_file_part(2015, "examples", 99, 2),
+            Err(ReadFileError::NotFound(path)) if path.ends_with("src/2015/examples/99.txt")
+        ));
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "stress.txt".parse(),
+            Ok(InputSource::Path("stress.txt".into()))
+        );
+    }
+}
@@ -2,44 +2,235 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::history::{History, Run};
+use advent_of_code::readme_benchmarks;
+use advent_of_code::{
+    config, print_day_header, print_part, print_total, solve_day, Day, Format, Limits, PartResult,
+    LIMIT_SUPPORTED, SOLVER_STACK_SIZE,
+};
+use std::{
+    collections::BTreeMap,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+struct Args {
+    days: Option<Vec<u8>>,
+    part: Option<u8>,
+    jobs: usize,
+    store: bool,
+    limits: Limits,
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+fn parse_args() -> Result<Args, String> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let days = args
+        .opt_value_from_fn("--days", parse_days)
+        .map_err(|e| e.to_string())?;
+    let part = args
+        .opt_value_from_str::<_, u8>("--part")
+        .map_err(|e| e.to_string())?;
+    let jobs = args
+        .opt_value_from_str::<_, usize>("--jobs")
+        .map_err(|e| e.to_string())?
+        .unwrap_or(1);
+    let store = args.contains("--store");
+    let timeout = args
+        .opt_value_from_str::<_, f64>("--timeout")
+        .map_err(|e| e.to_string())?;
+    let memory_limit = args
+        .opt_value_from_str::<_, u64>("--memory-limit")
+        .map_err(|e| e.to_string())?;
+
+    if part.is_some_and(|part| part != 1 && part != 2) {
+        return Err("part must be 1 or 2".into());
+    }
+    if jobs == 0 {
+        return Err("jobs must be at least 1".into());
+    }
+    if store && (days.is_some() || part.is_some()) {
+        return Err("`--store` needs the timings of all days and parts".into());
+    }
+    if store && config().bench.is_none() {
+        return Err("`--store` needs the medians of `--bench`, use `cargo time --store`".into());
+    }
+    if timeout.is_some_and(|timeout| !timeout.is_finite() || timeout <= 0_f64) {
+        return Err("timeout must be a positive number of seconds".into());
+    }
+    if memory_limit.is_some() && !LIMIT_SUPPORTED {
+        return Err("`--memory-limit` needs the `alloc-stats` feature".into());
+    }
+
+    Ok(Args {
+        days,
+        part,
+        jobs,
+        store,
+        limits: Limits {
+            timeout: timeout.map(Duration::from_secs_f64),
+            memory: memory_limit.map(|mib| mib * 1024 * 1024),
+        },
+    })
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+/// Parses a comma-separated list of days and inclusive ranges, e.g. `3-9,14`.
+fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{day}\", expected 1 to 25")),
+    };
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+    let mut days = vec![];
+    for selection in s.split(',') {
+        match selection.split_once('-') {
+            Some((start, end)) => days.extend(parse_day(start)?..=parse_day(end)?),
+            None => days.push(parse_day(selection)?),
+        }
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+fn print_day(results: &[PartResult]) {
+    if let Some(result) = results.first() {
+        print_day_header(result.day);
+    }
+    for result in results {
+        print_part(result);
+    }
+}
+
+/// Solves `days` on up to `jobs` threads, printing every day's results in order as soon as all previous days are done.
+fn run_days(
+    days: Vec<(Day, String)>,
+    part: Option<u8>,
+    jobs: usize,
+    limits: Limits,
+) -> Vec<PartResult> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (days, next) = (&days, &next);
+            thread::Builder::new()
+                .stack_size(SOLVER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some((day, input)) = days.get(i) else {
+                        break;
+                    };
+                    if sender
+                        .send((i, solve_day(*day, input, part, limits)))
+                        .is_err()
+                    {
+                        break;
+                    }
+                })
+                .expect("could not spawn worker thread");
+        }
+        drop(sender);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+        let mut pending = BTreeMap::new();
+        let mut results = vec![];
+        for (i, day_results) in receiver {
+            pending.insert(i, day_results);
+            while let Some(day_results) = pending.remove(&results.len()) {
+                print_day(&day_results);
+                results.push(day_results);
             }
+        }
+        results.into_iter().flatten().collect()
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    if config().input.is_some() {
+        eprintln!("`--input` only applies to a single day, use `AOC_INPUT_DIR` to read all inputs from another directory.");
+        process::exit(1);
+    }
+
+    // days without a registered solution are skipped, even if they were selected.
+    let days: Vec<(Day, String)> = advent_of_code::days::registry(config().year)
+        .into_iter()
+        .filter(|day| {
+            args.days
+                .as_ref()
+                .is_none_or(|selected| selected.contains(&day.day))
         })
-        .sum();
+        .map(|day| (day, advent_of_code::read_input_or_exit(day.year, day.day)))
+        .collect();
+
+    let results = run_days(days, args.part, args.jobs, args.limits);
+
+    let total: Duration = results.iter().map(|result| result.elapsed).sum();
+    print_total(total);
+
+    // timings of other inputs are not comparable, so they are kept out of the history.
+    if !config().has_custom_input() {
+        if let Err(e) = History::record(&Run::new(&results, args.days, args.part)) {
+            eprintln!("Failed to record timings: {e}");
+        }
+    }
+
+    if args.store {
+        match readme_benchmarks::update(&results) {
+            Ok(true) => println!("Stored updated benchmarks in README.md."),
+            Ok(false) => println!("Benchmarks in README.md are up to date."),
+            Err(e) => {
+                eprintln!("Failed to store benchmarks: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if config().verify {
+        let mismatches: Vec<&PartResult> = results
+            .iter()
+            .filter(|result| result.correct == Some(false))
+            .collect();
+
+        if config().format == Format::Text {
+            for result in &mismatches {
+                println!(
+                    "❌ Day {:02} part {} does not match its known answer.",
+                    result.day, result.part
+                );
+            }
+        }
+
+        if !mismatches.is_empty() {
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3-9,14"), Ok(vec![3, 4, 5, 6, 7, 8, 9, 14]));
+        assert_eq!(parse_days("14,2,2"), Ok(vec![2, 14]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("3-x").is_err());
+    }
 }
@@ -0,0 +1,252 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const CODE_BLOCK_INDENT: &str = "    ";
+
+/// Renders markdown for display in a terminal, wrapping text to `width` columns.
+///
+/// Supports the subset of markdown found in puzzle descriptions: headings, emphasis, links,
+/// lists, rules and inline / fenced code.
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut renderer = Renderer {
+        width,
+        ..Renderer::default()
+    };
+
+    for event in Parser::new(markdown) {
+        renderer.event(event);
+    }
+
+    renderer.output.trim_end().to_string() + "\n"
+}
+
+#[derive(Default)]
+struct Renderer {
+    width: usize,
+    output: String,
+    /// Inline content of the block being rendered, including ANSI escapes.
+    block: String,
+    styles: Vec<&'static str>,
+    /// Stack of list counters, `None` for unordered lists.
+    lists: Vec<Option<u64>>,
+    /// Prefix of the first line of a list item that has not been written yet.
+    item_prefix: Option<String>,
+    in_code_block: bool,
+}
+
+impl Renderer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                self.flush();
+                self.push_style(ANSI_BOLD);
+                if level == HeadingLevel::H1 {
+                    self.push_style(ANSI_UNDERLINE);
+                }
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                if level == HeadingLevel::H1 {
+                    self.pop_style();
+                }
+                self.pop_style();
+                self.flush();
+            }
+            Event::Start(Tag::Paragraph) => self.flush(),
+            Event::End(TagEnd::Paragraph) => self.flush(),
+            Event::Start(Tag::Emphasis) => self.push_style(ANSI_ITALIC),
+            Event::Start(Tag::Strong) => self.push_style(ANSI_BOLD),
+            Event::Start(Tag::Link { .. }) => self.push_style(ANSI_UNDERLINE),
+            Event::End(TagEnd::Emphasis | TagEnd::Strong | TagEnd::Link) => self.pop_style(),
+            Event::Start(Tag::CodeBlock(_)) => {
+                self.flush();
+                self.in_code_block = true;
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                self.in_code_block = false;
+                let code = std::mem::take(&mut self.block);
+                for line in code.trim_end_matches('\n').lines() {
+                    self.output.push_str(&format!(
+                        "{CODE_BLOCK_INDENT}{ANSI_CYAN}{line}{ANSI_RESET}\n"
+                    ));
+                }
+                self.output.push('\n');
+            }
+            Event::Start(Tag::List(start)) => {
+                self.flush();
+                self.lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.output.push('\n');
+                }
+            }
+            Event::Start(Tag::Item) => {
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}.", *n - 1)
+                    }
+                    _ => "-".to_string(),
+                };
+                self.item_prefix = Some(format!("{}{bullet} ", "  ".repeat(depth)));
+            }
+            Event::End(TagEnd::Item) => self.flush(),
+            Event::Code(code) => {
+                self.block.push_str(ANSI_CYAN);
+                self.block.push_str(&code);
+                self.block.push_str(ANSI_RESET);
+                self.restore_styles();
+            }
+            Event::Text(text) => self.block.push_str(&text),
+            Event::SoftBreak => self.block.push(' '),
+            Event::HardBreak => self.block.push('\n'),
+            Event::Rule => {
+                self.flush();
+                self.output.push_str(&"─".repeat(self.width));
+                self.output.push_str("\n\n");
+            }
+            _ => (),
+        }
+    }
+
+    fn push_style(&mut self, style: &'static str) {
+        self.styles.push(style);
+        self.block.push_str(style);
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+        self.block.push_str(ANSI_RESET);
+        self.restore_styles();
+    }
+
+    fn restore_styles(&mut self) {
+        for style in &self.styles {
+            self.block.push_str(style);
+        }
+    }
+
+    /// Writes the pending block to the output, word-wrapped to the configured width.
+    fn flush(&mut self) {
+        if self.in_code_block {
+            return;
+        }
+
+        let block = std::mem::take(&mut self.block);
+        if block.trim().is_empty() {
+            return;
+        }
+
+        let in_list = !self.lists.is_empty();
+        let indent = " ".repeat(
+            self.item_prefix
+                .as_ref()
+                .map(|prefix| prefix.len())
+                .unwrap_or(self.lists.len().saturating_sub(1) * 2 + if in_list { 2 } else { 0 }),
+        );
+        let first_prefix = self.item_prefix.take().unwrap_or_else(|| indent.clone());
+
+        for (i, line) in block.split('\n').enumerate() {
+            let prefix = if i == 0 { &first_prefix } else { &indent };
+            self.output
+                .push_str(&wrap(line, prefix, &indent, self.width));
+        }
+
+        if !in_list {
+            self.output.push('\n');
+        }
+    }
+}
+
+/// Greedily fills lines of at most `width` visible characters.
+fn wrap(text: &str, first_prefix: &str, indent: &str, width: usize) -> String {
+    let mut output = String::new();
+    let mut line = first_prefix.to_string();
+    let mut line_width = first_prefix.len();
+    let mut line_is_empty = true;
+
+    for word in text.split(' ').filter(|word| !word.is_empty()) {
+        let word_width = visible_width(word);
+        if !line_is_empty && line_width + 1 + word_width > width {
+            output.push_str(line.trim_end());
+            output.push('\n');
+            line = indent.to_string();
+            line_width = indent.len();
+            line_is_empty = true;
+        }
+        if !line_is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+        line_is_empty = false;
+    }
+
+    output.push_str(line.trim_end());
+    output.push('\n');
+    output
+}
+
+/// Counts characters, skipping ANSI escape sequences.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => (),
+            _ => width += 1,
+        }
+    }
+
+    width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let markdown = "\\--- Day 1: Not Quite Lisp ---\n----------\n\nSanta is trying to deliver *presents* in a large apartment building.\n\nFor example:\n\n* `(())` and `()()` both result in floor `0`.\n* `)))` results in floor `-3`.\n\n```\n(()(()(\n```\n";
+
+        assert_eq!(
+            render(markdown, 30),
+            format!(
+                "{ANSI_BOLD}--- Day 1: Not Quite Lisp ---{ANSI_RESET}\n\
+                 \n\
+                 Santa is trying to deliver\n\
+                 {ANSI_ITALIC}presents{ANSI_RESET} in a large apartment\n\
+                 building.\n\
+                 \n\
+                 For example:\n\
+                 \n\
+                 - {ANSI_CYAN}(()){ANSI_RESET} and {ANSI_CYAN}()(){ANSI_RESET} both result in\n  \
+                 floor {ANSI_CYAN}0{ANSI_RESET}.\n\
+                 - {ANSI_CYAN}))){ANSI_RESET} results in floor {ANSI_CYAN}-3{ANSI_RESET}.\n\
+                 \n\
+                 {CODE_BLOCK_INDENT}{ANSI_CYAN}(()(()({ANSI_RESET}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_visible_width() {
+        assert_eq!(visible_width("plain"), 5);
+        assert_eq!(visible_width(&format!("{ANSI_BOLD}bold{ANSI_RESET}")), 4);
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::sync::atomic::AtomicBool;
+
+use serde::Serialize;
+
+/// Whether a memory limit can be enforced, which needs the counting allocator of the `alloc-stats` feature.
+pub const LIMIT_SUPPORTED: bool = cfg!(feature = "alloc-stats");
+
+/// Heap usage of a single run of a solver, collected by the counting allocator of the `alloc-stats` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by all allocations.
+    pub allocated_bytes: u64,
+    /// Most bytes that were allocated and not yet freed at the same time.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, returning the heap usage of the current thread while it ran.
+///
+/// Returns `None` for the stats if the `alloc-stats` feature is disabled.
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    (f(), None)
+}
+
+/// Runs `f`, returning the heap usage of the current thread while it ran.
+///
+/// Memory allocated by other threads is not counted, solutions are expected to run on a single thread.
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    counting::reset();
+    let value = f();
+    (value, Some(counting::stats()))
+}
+
+/// Runs `f`, limiting the live heap bytes of each part it measures on the current thread to `bytes`.
+///
+/// A thread exceeding the limit sets `exceeded` and is parked inside the allocator for good, so it stops growing.
+/// The limit is not enforced unless [`LIMIT_SUPPORTED`] is true.
+pub fn with_limit<T>(bytes: u64, exceeded: &AtomicBool, f: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "alloc-stats")]
+    {
+        counting::LIMIT.set(Some((bytes, exceeded)));
+        let value = f();
+        counting::LIMIT.set(None);
+        value
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        let _ = (bytes, exceeded);
+        f()
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use super::MemoryStats;
+
+    /// Wraps the system allocator, counting the allocations of each thread.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    // the counters must not allocate themselves, which `const` thread locals without destructors guarantee.
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+        /// Bytes allocated minus bytes freed since the last reset, negative if older memory was freed.
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+        /// Set by [`super::with_limit`] only while the flag it points to is borrowed.
+        pub(super) static LIMIT: Cell<Option<(u64, *const AtomicBool)>> = const { Cell::new(None) };
+    }
+
+    fn record_alloc(size: usize) {
+        // `try_with` fails while the thread is shutting down, those allocations are irrelevant.
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+        let _ = LIVE.try_with(|live| {
+            let value = live.get() + size as i64;
+            live.set(value);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(value)));
+            if let Ok(Some((limit, exceeded))) = LIMIT.try_with(Cell::get) {
+                if value > limit as i64 {
+                    // SAFETY: `with_limit` clears the limit before its borrow of the flag ends.
+                    unsafe { &*exceeded }.store(true, Ordering::Relaxed);
+                    // a thread can't be stopped from the outside, so it waits here until the process exits.
+                    loop {
+                        thread::sleep(Duration::from_secs(3600));
+                    }
+                }
+            }
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    pub(super) fn reset() {
+        ALLOCATIONS.set(0);
+        ALLOCATED.set(0);
+        LIVE.set(0);
+        PEAK.set(0);
+    }
+
+    pub(super) fn stats() -> MemoryStats {
+        MemoryStats {
+            allocations: ALLOCATIONS.get(),
+            allocated_bytes: ALLOCATED.get(),
+            peak_bytes: PEAK.get().max(0) as u64,
+        }
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(8 * 1024 * 1024), "8.00 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let first = vec![0_u8; 1000];
+            drop(first);
+            let second = vec![0_u8; 600];
+            second.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(len, 600);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.allocated_bytes, 1600);
+        assert_eq!(stats.peak_bytes, 1000);
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, io, time::Duration};
+
+use crate::PartResult;
+
+const README_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/README.md");
+/// Encloses the benchmark table in the readme, the section is expected to start and end with it.
+const MARKER: &str = "<!--- benchmarking table --->";
+/// Timings are stored with this many significant digits, so reruns within the noise leave the table unchanged.
+const SIGNIFICANT_DIGITS: u32 = 2;
+
+#[derive(Debug)]
+pub enum ReadmeError {
+    IoError(io::Error),
+    MarkerNotFound,
+}
+
+impl Display for ReadmeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadmeError::IoError(e) => write!(f, "could not access README.md: {e}"),
+            ReadmeError::MarkerNotFound => write!(
+                f,
+                "could not find two \"{MARKER}\" markers in README.md, add them where the table should go."
+            ),
+        }
+    }
+}
+
+/// Rewrites the benchmark section of README.md with the timings of `results`.
+///
+/// Returns whether the file changed, an unchanged table leaves the file untouched.
+pub fn update(results: &[PartResult]) -> Result<bool, ReadmeError> {
+    let readme = fs::read_to_string(README_PATH).map_err(ReadmeError::IoError)?;
+    let updated = replace_section(&readme, &render_table(results))?;
+
+    if updated == readme {
+        return Ok(false);
+    }
+
+    fs::write(README_PATH, updated).map_err(ReadmeError::IoError)?;
+    Ok(true)
+}
+
+fn replace_section(readme: &str, table: &str) -> Result<String, ReadmeError> {
+    let start = readme.find(MARKER).ok_or(ReadmeError::MarkerNotFound)? + MARKER.len();
+    let end = readme[start..]
+        .find(MARKER)
+        .ok_or(ReadmeError::MarkerNotFound)?
+        + start;
+
+    Ok(format!("{}\n{table}{}", &readme[..start], &readme[end..]))
+}
+
+/// Rounds `elapsed` to [`SIGNIFICANT_DIGITS`], e.g. 1.547ms to 1.5ms.
+fn round(elapsed: Duration) -> Duration {
+    let nanos = elapsed.as_nanos();
+    let digits = nanos.checked_ilog10().map_or(0, |log| log + 1);
+    let step = 10_u128.pow(digits.saturating_sub(SIGNIFICANT_DIGITS));
+    let rounded = (nanos + step / 2) / step * step;
+    Duration::from_nanos(rounded as u64)
+}
+
+fn render_table(results: &[PartResult]) -> String {
+    let mut days: Vec<(u16, u8)> = results
+        .iter()
+        .map(|result| (result.year, result.day))
+        .collect();
+    days.dedup();
+
+    let elapsed = |year: u16, day: u8, part: u8| {
+        results
+            .iter()
+            .find(|result| {
+                result.year == year
+                    && result.day == day
+                    && result.part == part
+                    && result.answer.is_some()
+            })
+            .map(|result| result.elapsed)
+    };
+    let format = |elapsed: Option<Duration>| match elapsed {
+        Some(elapsed) => format!("`{:?}`", round(elapsed)),
+        None => "-".to_string(),
+    };
+
+    let mut table = String::from(
+        "## Benchmarks\n\n| Day | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: |\n",
+    );
+    let mut total = Duration::ZERO;
+
+    for (year, day) in days {
+        let (part_one, part_two) = (elapsed(year, day, 1), elapsed(year, day, 2));
+        let day_total = part_one.unwrap_or_default() + part_two.unwrap_or_default();
+        total += day_total;
+
+        table.push_str(&format!(
+            "| [Day {day}](./src/days/y{year}/day{day:02}.rs) | {} | {} | {} |\n",
+            format(part_one),
+            format(part_two),
+            format(Some(day_total))
+        ));
+    }
+
+    table.push_str(&format!("\n**Total: {:?}**\n", round(total)));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Status;
+
+    fn result(day: u8, part: u8, elapsed_us: u64, solved: bool) -> PartResult {
+        PartResult {
+            year: 2015,
+            day,
+            part,
+            answer: solved.then(|| "42".to_string()),
+            elapsed: Duration::from_micros(elapsed_us),
+            status: if solved {
+                Status::Solved
+            } else {
+                Status::NotSolved
+            },
+            error: None,
+            expected: None,
+            correct: None,
+            bench: None,
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let results = [
+            result(1, 1, 20, true),
+            result(1, 2, 30, true),
+            result(2, 1, 1547, true),
+            result(2, 2, 0, false),
+        ];
+        assert_eq!(
+            render_table(&results),
+            "## Benchmarks\n\n\
+             | Day | Part 1 | Part 2 | Total |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/days/y2015/day01.rs) | `20µs` | `30µs` | `50µs` |\n\
+             | [Day 2](./src/days/y2015/day02.rs) | `1.5ms` | - | `1.5ms` |\n\
+             \n\
+             **Total: 1.6ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(Duration::from_nanos(0)), Duration::ZERO);
+        assert_eq!(round(Duration::from_nanos(7)), Duration::from_nanos(7));
+        assert_eq!(
+            round(Duration::from_nanos(1_549)),
+            Duration::from_nanos(1_500)
+        );
+        assert_eq!(round(Duration::from_micros(995)), Duration::from_millis(1));
+        assert_eq!(
+            round(Duration::from_millis(12_345)),
+            Duration::from_millis(12_000)
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# Title\n\n{MARKER}\nold table\n{MARKER}\n\n## Usage\n");
+
+        let updated = replace_section(&readme, "new table\n").unwrap();
+        assert_eq!(
+            updated,
+            format!("# Title\n\n{MARKER}\nnew table\n{MARKER}\n\n## Usage\n")
+        );
+        assert_eq!(replace_section(&updated, "new table\n").unwrap(), updated);
+
+        assert!(matches!(
+            replace_section("# Title\n", "new table\n"),
+            Err(ReadmeError::MarkerNotFound)
+        ));
+    }
+}
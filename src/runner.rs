@@ -0,0 +1,508 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    bench, config, format_bytes, memory, Answers, BenchStats, Day, Format, MemoryStats,
+    PartOutcome, PartOutput, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Solutions may keep large arrays on the stack, which the default of 2MiB for spawned threads does not fit.
+pub const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// How often [`solve_day`] checks whether a part exceeded the memory limit.
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    NotSolved,
+    /// The solver returned an error.
+    Failed,
+    Panicked,
+    /// The day did not finish within its time limit.
+    TimedOut,
+    /// The part allocated more than the memory limit.
+    MemoryExceeded,
+}
+
+/// The outcome of running one part of a solution.
+///
+/// In benchmark mode, `elapsed` holds the median of all measured iterations.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+    pub status: Status,
+    /// The error message of a failed part, the panic message of a panicked one or the limit a part exceeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The known answer from `src/<year>/answers/NN.toml`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Whether `answer` matches `expected`, `None` if no answer is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// Heap usage of the first run, only collected with the `alloc-stats` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+pub(crate) fn serialize_nanos<S: serde::Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_nanos())
+}
+
+/// Runs a single part of a solution and prints its result in the configured format.
+///
+/// Panics are caught, so a broken part does not take the remaining ones down with it.
+pub fn run_part<O: PartOutput>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> O,
+    input: &str,
+) -> PartResult {
+    let result = solve_part(year, day, part, solver, input);
+    print_part(&result);
+    result
+}
+
+/// Runs a single part of a solution like [`run_part`], without printing its result.
+pub fn solve_part<O: PartOutput>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> O,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let (result, memory) = memory::measure(|| catch_panic(|| solver(input).into_outcome()));
+    let mut elapsed = timer.elapsed();
+
+    let (answer, status, error) = match result {
+        Ok(PartOutcome::Solved(answer)) => (Some(answer), Status::Solved, None),
+        Ok(PartOutcome::NotSolved) => (None, Status::NotSolved, None),
+        Ok(PartOutcome::Failed(e)) => (None, Status::Failed, Some(e)),
+        Err(message) => (None, Status::Panicked, Some(message)),
+    };
+
+    // only solved parts are benchmarked, there is nothing meaningful to measure otherwise.
+    let bench = match config().bench {
+        Some(budget) if status == Status::Solved => {
+            let stats = bench::bench(&solver, input, budget);
+            elapsed = stats.median;
+            Some(stats)
+        }
+        _ => None,
+    };
+
+    let (expected, correct) = check_answer(year, day, part, answer.as_ref());
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        error,
+        expected,
+        correct,
+        bench,
+        memory,
+    }
+}
+
+/// Looks up the known answer of a part, returning it and whether `answer` matches it.
+fn check_answer(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<&String>,
+) -> (Option<String>, Option<bool>) {
+    if config().has_custom_input() {
+        return (None, None);
+    }
+
+    let expected = match Answers::load(year, day) {
+        Ok(answers) => answers.get(part),
+        Err(e) => {
+            eprintln!("Failed to load answers for day {day:02}: {e}");
+            None
+        }
+    };
+    let correct = expected.as_ref().map(|expected| answer == Some(expected));
+    (expected, correct)
+}
+
+type Solver = fn(&str) -> PartOutcome;
+
+/// Resource limits applied to each day by [`solve_day`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Time after which the parts of a day that are not done yet are reported as timed out.
+    pub timeout: Option<Duration>,
+    /// Live heap bytes a part may use, only enforced with the `alloc-stats` feature.
+    pub memory: Option<u64>,
+}
+
+/// Solves the selected parts of a day without printing them, `None` selects both parts.
+///
+/// With limits, the day runs on a thread of its own. A day that exceeds a limit can't be stopped, its thread is left
+/// behind and the remaining parts are reported as timed out or out of memory.
+pub fn solve_day(day: Day, input: &str, part: Option<u8>, limits: Limits) -> Vec<PartResult> {
+    let parts: Vec<(u8, Solver)> = [(1, day.part_one), (2, day.part_two)]
+        .into_iter()
+        .filter(|(number, _)| part.is_none_or(|part| part == *number))
+        .collect();
+
+    if limits == Limits::default() {
+        return parts
+            .into_iter()
+            .map(|(number, solver)| solve_part(day.year, day.day, number, solver, input))
+            .collect();
+    }
+
+    // the thread may outlive this call, so it owns everything it uses.
+    let exceeded = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel();
+    let (owned_input, solvers, solver_exceeded) =
+        (input.to_string(), parts.clone(), Arc::clone(&exceeded));
+    thread::Builder::new()
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let solve = || {
+                for (number, solver) in solvers {
+                    let result = solve_part(day.year, day.day, number, solver, &owned_input);
+                    if sender.send(result).is_err() {
+                        break;
+                    }
+                }
+            };
+            match limits.memory {
+                Some(bytes) => memory::with_limit(bytes, &solver_exceeded, solve),
+                None => solve(),
+            }
+        })
+        .expect("could not spawn solver thread");
+
+    let start = Instant::now();
+    let mut results = vec![];
+    let limit_status = loop {
+        if results.len() == parts.len() {
+            break None;
+        }
+        if exceeded.load(Ordering::Relaxed) {
+            break Some(Status::MemoryExceeded);
+        }
+        let remaining = match limits.timeout {
+            Some(timeout) if start.elapsed() >= timeout => break Some(Status::TimedOut),
+            Some(timeout) => timeout - start.elapsed(),
+            None => Duration::MAX,
+        };
+        // a part exceeding the memory limit stops sending, so the flag is polled in between.
+        let wait = match limits.memory {
+            Some(_) => remaining.min(MEMORY_POLL_INTERVAL),
+            None => remaining,
+        };
+        match receiver.recv_timeout(wait) {
+            Ok(result) => results.push(result),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break None,
+        }
+    };
+
+    if let Some(status) = limit_status {
+        // the part that was running when the limit hit takes the remaining time, later parts never started.
+        let done: Duration = results.iter().map(|result| result.elapsed).sum();
+        let mut elapsed = start.elapsed().saturating_sub(done);
+        let error = match status {
+            Status::TimedOut => format!("timed out after {:?}", limits.timeout.unwrap_or_default()),
+            _ => format!(
+                "exceeded the memory limit of {}",
+                format_bytes(limits.memory.unwrap_or_default())
+            ),
+        };
+        for &(number, _) in &parts[results.len()..] {
+            let (expected, correct) = check_answer(day.year, day.day, number, None);
+            results.push(PartResult {
+                year: day.year,
+                day: day.day,
+                part: number,
+                answer: None,
+                elapsed,
+                status,
+                error: Some(error.clone()),
+                expected,
+                correct,
+                bench: None,
+                memory: None,
+            });
+            elapsed = Duration::ZERO;
+        }
+    }
+
+    results
+}
+
+/// Prints the result of a part in the configured format.
+pub fn print_part(result: &PartResult) {
+    match config().format {
+        Format::Text => {
+            println!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄", result.part);
+            let check = match (result.correct, &result.expected) {
+                (Some(true), _) => " ✅".to_string(),
+                (Some(false), Some(expected)) => format!(" ❌ (expected: {expected})"),
+                _ => String::new(),
+            };
+            match (&result.answer, result.status) {
+                (Some(answer), _) => match &result.bench {
+                    Some(stats) => {
+                        println!(
+                            "{answer}{check} {ANSI_ITALIC}(min: {:.2?}, mean: {:.2?}, median: {:.2?}, p95: {:.2?}, iterations: {}){ANSI_RESET}",
+                            stats.min, stats.mean, stats.median, stats.p95, stats.iterations
+                        );
+                    }
+                    None => {
+                        println!(
+                            "{answer}{check} {ANSI_ITALIC}(elapsed: {:.2?}){ANSI_RESET}",
+                            result.elapsed
+                        );
+                    }
+                },
+                (None, Status::Failed | Status::Panicked) => {
+                    let verb = match result.status {
+                        Status::Failed => "failed",
+                        _ => "panicked",
+                    };
+                    println!(
+                        "{verb}: {}{check} {ANSI_ITALIC}(elapsed: {:.2?}){ANSI_RESET}",
+                        result.error.as_deref().unwrap_or_default(),
+                        result.elapsed
+                    );
+                }
+                (None, Status::TimedOut | Status::MemoryExceeded) => {
+                    println!("{}.{check}", result.error.as_deref().unwrap_or_default());
+                }
+                (None, _) => {
+                    println!("not solved.{check}")
+                }
+            }
+            if let Some(memory) = &result.memory {
+                println!(
+                    "{ANSI_ITALIC}(allocations: {}, allocated: {}, peak: {}){ANSI_RESET}",
+                    memory.allocations,
+                    format_bytes(memory.allocated_bytes),
+                    format_bytes(memory.peak_bytes)
+                );
+            }
+        }
+        Format::Json => {
+            println!("{}", serde_json::to_string(result).unwrap());
+        }
+    }
+}
+
+thread_local! {
+    /// Whether panics on this thread are caught by [`catch_panic`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic caught on this thread.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, returning the panic message and location if it panics.
+///
+/// While `f` runs, the default panic hook is silenced, the message is reported with the part instead.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                let location = info.location().map(|location| location.to_string());
+                PANIC_LOCATION.set(location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    CATCHING.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(false);
+
+    result.map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("{message} at {location}"),
+            None => message,
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Prints the banner preceding a day's results in `cargo all`.
+pub fn print_day_header(day: u8) {
+    if config().format == Format::Text {
+        println!("----------");
+        println!("{ANSI_BOLD}| Day {day:02} |{ANSI_RESET}");
+        println!("----------");
+    }
+}
+
+/// Prints the combined runtime of all parts in `cargo all`.
+pub fn print_total(total: Duration) {
+    if config().format == Format::Text {
+        println!(
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            total.as_secs_f64() * 1000_f64
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_result_json() {
+        let result = PartResult {
+            year: 2015,
+            day: 7,
+            part: 2,
+            answer: Some("42".into()),
+            elapsed: Duration::from_micros(1500),
+            status: Status::Solved,
+            error: None,
+            expected: None,
+            correct: None,
+            bench: None,
+            memory: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2015,"day":7,"part":2,"answer":"42","elapsed_ns":1500000,"status":"solved"}"#
+        );
+
+        let result = PartResult {
+            answer: None,
+            status: Status::NotSolved,
+            ..result
+        };
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2015,"day":7,"part":2,"answer":null,"elapsed_ns":1500000,"status":"not_solved"}"#
+        );
+
+        let result = PartResult {
+            expected: Some("42".into()),
+            correct: Some(false),
+            ..result
+        };
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2015,"day":7,"part":2,"answer":null,"elapsed_ns":1500000,"status":"not_solved","expected":"42","correct":false}"#
+        );
+
+        let result = PartResult {
+            memory: Some(MemoryStats {
+                allocations: 3,
+                allocated_bytes: 4096,
+                peak_bytes: 2048,
+            }),
+            ..result
+        };
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2015,"day":7,"part":2,"answer":null,"elapsed_ns":1500000,"status":"not_solved","expected":"42","correct":false,"memory":{"allocations":3,"allocated_bytes":4096,"peak_bytes":2048}}"#
+        );
+
+        let result = PartResult {
+            status: Status::Failed,
+            error: Some("invalid line 3".into()),
+            expected: None,
+            correct: None,
+            memory: None,
+            ..result
+        };
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2015,"day":7,"part":2,"answer":null,"elapsed_ns":1500000,"status":"failed","error":"invalid line 3"}"#
+        );
+    }
+
+    #[test]
+    fn test_solve_day_timeout() {
+        let day = Day {
+            year: 2015,
+            day: 99,
+            part_one: |input| PartOutcome::Solved(input.len().to_string()),
+            part_two: |_| {
+                thread::sleep(Duration::from_secs(1));
+                PartOutcome::NotSolved
+            },
+        };
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            memory: None,
+        };
+
+        let results = solve_day(day, "abc", None, limits);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer.as_deref(), Some("3"));
+        assert_eq!(results[1].status, Status::TimedOut);
+        assert_eq!(results[1].error.as_deref(), Some("timed out after 100ms"));
+
+        let results = solve_day(day, "abc", Some(1), limits);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, Status::Solved);
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let error = catch_panic(|| -> u32 { panic!("bad input: {}", 7) }).unwrap_err();
+        assert!(
+            error.starts_with("bad input: 7 at src/runner.rs:"),
+            "{error}"
+        );
+
+        let error = catch_panic(|| "x".parse::<u32>().unwrap()).unwrap_err();
+        assert!(error.starts_with("called `Result::unwrap()` on an `Err` value"));
+    }
+}
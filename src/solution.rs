@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+/// A puzzle solution for a single day.
+///
+/// `parse` turns the raw puzzle input into whatever representation both parts share,
+/// `part_one` and `part_two` compute the answers from it.
+/// Parts return either `Option<T>` (`None` while unsolved) or `Result<T, E>`, see [`PartOutput`].
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    /// Why `parse` rejected an input, `std::convert::Infallible` if it accepts any input.
+    type ParseError: Display;
+    type PartOne: PartOutput;
+    type PartTwo: PartOutput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo;
+
+    /// Parses `input` and solves part one.
+    ///
+    /// # Panics
+    ///
+    /// If `input` can't be parsed, the registered [`Day`] reports this as a failed part instead.
+    fn solve_part_one(input: &str) -> Self::PartOne {
+        Self::part_one(&parse_or_panic::<Self>(input))
+    }
+
+    /// Parses `input` and solves part two.
+    ///
+    /// # Panics
+    ///
+    /// If `input` can't be parsed, the registered [`Day`] reports this as a failed part instead.
+    fn solve_part_two(input: &str) -> Self::PartTwo {
+        Self::part_two(&parse_or_panic::<Self>(input))
+    }
+}
+
+fn parse_or_panic<S: Solution + ?Sized>(input: &str) -> S::Input<'_> {
+    S::parse(input).unwrap_or_else(|e| panic!("could not parse the input: {e}"))
+}
+
+/// What running a part produced, independent of the solver's return type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartOutcome {
+    Solved(String),
+    NotSolved,
+    /// The solver returned an error, holding its message.
+    Failed(String),
+}
+
+/// A return type of [`Solution::part_one`] and [`Solution::part_two`].
+pub trait PartOutput {
+    fn into_outcome(self) -> PartOutcome;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn into_outcome(self) -> PartOutcome {
+        match self {
+            Some(answer) => PartOutcome::Solved(answer.to_string()),
+            None => PartOutcome::NotSolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn into_outcome(self) -> PartOutcome {
+        match self {
+            Ok(answer) => PartOutcome::Solved(answer.to_string()),
+            Err(e) => PartOutcome::Failed(e.to_string()),
+        }
+    }
+}
+
+impl PartOutput for PartOutcome {
+    fn into_outcome(self) -> PartOutcome {
+        self
+    }
+}
+
+/// A type-erased registry entry for a [`Solution`].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: fn(&str) -> PartOutcome,
+    pub part_two: fn(&str) -> PartOutcome,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part_one: |input| solve::<S, _>(input, S::part_one),
+            part_two: |input| solve::<S, _>(input, S::part_two),
+        }
+    }
+}
+
+/// Parses `input` and solves a part, reporting an input that can't be parsed as a failure.
+fn solve<S: Solution, O: PartOutput>(input: &str, part: fn(&S::Input<'_>) -> O) -> PartOutcome {
+    match S::parse(input) {
+        Ok(input) => part(&input).into_outcome(),
+        Err(e) => PartOutcome::Failed(format!("could not parse the input: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_outcome() {
+        assert_eq!(Some(42).into_outcome(), PartOutcome::Solved("42".into()));
+        assert_eq!(None::<u32>.into_outcome(), PartOutcome::NotSolved);
+        assert_eq!(
+            Ok::<_, String>("abc").into_outcome(),
+            PartOutcome::Solved("abc".into())
+        );
+        assert_eq!(
+            Err::<u32, _>("bad input").into_outcome(),
+            PartOutcome::Failed("bad input".into())
+        );
+    }
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input<'a> = Vec<u32>;
+        type ParseError = std::num::ParseIntError;
+        type PartOne = Option<u32>;
+        type PartTwo = Option<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::ParseError> {
+            input.lines().map(str::parse).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Self::PartOne {
+            Some(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Self::PartTwo {
+            input.iter().max().copied()
+        }
+    }
+
+    #[test]
+    fn test_day_of() {
+        let day = Day::of::<Numbers>();
+        assert_eq!((day.part_one)("1\n2"), PartOutcome::Solved("3".into()));
+        assert_eq!(
+            (day.part_two)("1\nx"),
+            PartOutcome::Failed("could not parse the input: invalid digit found in string".into())
+        );
+    }
+}
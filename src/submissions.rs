@@ -0,0 +1,288 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::aoc_client::{AocClientError, Hint, Verdict};
+
+const SUBMISSIONS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/submissions.jsonl");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    AlreadyCompleted,
+    RateLimited,
+    Unknown,
+}
+
+/// A submitted answer and the server's response, one per line in `src/submissions.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix timestamp of the submission.
+    pub submitted_at: u64,
+    /// Unix timestamp before which the server will not accept another answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+impl Submission {
+    /// Records the result of submitting `answer`.
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        result: &Result<Verdict, AocClientError>,
+    ) -> Option<Self> {
+        let (outcome, wait) = match result {
+            Ok(Verdict::Correct) => (Outcome::Correct, None),
+            Ok(Verdict::Incorrect { hint, wait }) => {
+                let outcome = match hint {
+                    Some(Hint::TooHigh) => Outcome::TooHigh,
+                    Some(Hint::TooLow) => Outcome::TooLow,
+                    None => Outcome::Incorrect,
+                };
+                (outcome, *wait)
+            }
+            Ok(Verdict::AlreadyCompleted) => (Outcome::AlreadyCompleted, None),
+            Ok(Verdict::Unknown(_)) => (Outcome::Unknown, None),
+            Err(AocClientError::RateLimited(wait)) => (Outcome::RateLimited, *wait),
+            // the answer never reached the server, so there is nothing to remember.
+            Err(_) => return None,
+        };
+
+        let submitted_at = now();
+        Some(Self {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at,
+            retry_at: wait.map(|wait| submitted_at + wait.as_secs()),
+        })
+    }
+}
+
+/// Why an answer is not sent to the server.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong,
+    NotBelow(String),
+    NotAbove(String),
+    Wait(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Refusal::NotBelow(bound) => {
+                write!(f, "\"{bound}\" was too high, so this answer is too.")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "\"{bound}\" was too low, so this answer is too.")
+            }
+            Refusal::Wait(wait) => write!(
+                f,
+                "the server asked to wait, try again in {}s.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(SUBMISSIONS_PATH) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, io::Error> {
+        let submissions = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { submissions })
+    }
+
+    /// Appends a submission to the log file.
+    pub fn record(&mut self, submission: Submission) -> Result<(), io::Error> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(SUBMISSIONS_PATH)?;
+        writeln!(file, "{}", serde_json::to_string(&submission)?)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Checks whether `answer` is worth sending to the server.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), Refusal> {
+        self.check_at(year, day, part, answer, now())
+    }
+
+    fn check_at(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        // the server limits the whole account, so a wait applies to every day and year.
+        let wait = self
+            .submissions
+            .iter()
+            .filter_map(|s| s.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now);
+        if let Some(retry_at) = wait {
+            return Err(Refusal::Wait(Duration::from_secs(retry_at - now)));
+        }
+
+        let submissions = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+
+        let value = answer.parse::<i128>().ok();
+        for submission in submissions {
+            match submission.outcome {
+                Outcome::Correct => {
+                    return Err(Refusal::AlreadySolved(submission.answer.clone()));
+                }
+                Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+                    if submission.answer == answer =>
+                {
+                    return Err(Refusal::KnownWrong);
+                }
+                Outcome::TooHigh | Outcome::TooLow => {
+                    let bound = submission.answer.parse::<i128>().ok();
+                    match (submission.outcome, value, bound) {
+                        (Outcome::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                            return Err(Refusal::NotBelow(submission.answer.clone()));
+                        }
+                        (Outcome::TooLow, Some(value), Some(bound)) if value <= bound => {
+                            return Err(Refusal::NotAbove(submission.answer.clone()));
+                        }
+                        _ => (),
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome, retry_at: Option<u64>) -> Submission {
+        Submission {
+            year: 2015,
+            day: 1,
+            part,
+            answer: answer.into(),
+            outcome,
+            submitted_at: 100,
+            retry_at,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let log = SubmissionLog {
+            submissions: vec![
+                submission(1, "300", Outcome::TooHigh, Some(160)),
+                submission(1, "100", Outcome::TooLow, None),
+                submission(1, "abc", Outcome::Incorrect, None),
+                submission(2, "42", Outcome::Correct, None),
+            ],
+        };
+
+        assert_eq!(
+            log.check_at(2015, 1, 1, "200", 150),
+            Err(Refusal::Wait(Duration::from_secs(10)))
+        );
+        assert_eq!(log.check_at(2015, 1, 1, "200", 160), Ok(()));
+        assert_eq!(
+            log.check_at(2015, 1, 1, "300", 160),
+            Err(Refusal::KnownWrong)
+        );
+        assert_eq!(
+            log.check_at(2015, 1, 1, "abc", 160),
+            Err(Refusal::KnownWrong)
+        );
+        assert_eq!(
+            log.check_at(2015, 1, 1, "301", 160),
+            Err(Refusal::NotBelow("300".into()))
+        );
+        assert_eq!(
+            log.check_at(2015, 1, 1, "99", 160),
+            Err(Refusal::NotAbove("100".into()))
+        );
+        assert_eq!(
+            log.check_at(2015, 1, 2, "43", 160),
+            Err(Refusal::AlreadySolved("42".into()))
+        );
+        assert_eq!(
+            log.check_at(2016, 1, 1, "300", 150),
+            Err(Refusal::Wait(Duration::from_secs(10)))
+        );
+        assert_eq!(log.check_at(2016, 1, 1, "300", 160), Ok(()));
+    }
+
+    #[test]
+    fn test_parse() {
+        let log = SubmissionLog::parse(
+            "{\"year\":2015,\"day\":1,\"part\":1,\"answer\":\"300\",\"outcome\":\"too_high\",\"submitted_at\":100,\"retry_at\":160}\n",
+        )
+        .unwrap();
+        assert_eq!(
+            log.submissions,
+            vec![submission(1, "300", Outcome::TooHigh, Some(160))]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    UnknownPlaceholder(String),
+    Unclosed,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::UnknownPlaceholder(name) => {
+                write!(f, "unknown placeholder \"{{{{{name}}}}}\".")
+            }
+            TemplateError::Unclosed => write!(f, "a \"{{{{\" is not closed by \"}}}}\"."),
+        }
+    }
+}
+
+/// What a scaffolded part of an `Option` return type returns until it is solved.
+pub const UNSOLVED_OPTION: &str = "None";
+/// What a scaffolded part of a `Result` return type returns until it is solved.
+pub const UNSOLVED_RESULT: &str = "Err(\"not solved yet\".into())";
+
+/// Returns what a part of `return_type` returns until it is solved, `None` unless it is an `Option` or a `Result`.
+pub fn unsolved(return_type: &str) -> Option<&'static str> {
+    let return_type = return_type.trim_start();
+    if return_type.starts_with("Option<") {
+        Some(UNSOLVED_OPTION)
+    } else if return_type.starts_with("Result<") {
+        Some(UNSOLVED_RESULT)
+    } else {
+        None
+    }
+}
+
+/// Replaces every `{{name}}` in `template` with the value of `name` in `values`.
+///
+/// Text outside of placeholders is copied as is, and inserted values are not scanned for placeholders again.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, TemplateError> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let end = rest[start..].find("}}").ok_or(TemplateError::Unclosed)? + start;
+
+        let name = rest[start + 2..end].trim();
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| TemplateError::UnknownPlaceholder(name.to_string()))?;
+        output.push_str(value);
+
+        rest = &rest[end + 2..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = [("day", "7"), ("day_padded", "07"), ("title", "{{day}}")];
+
+        assert_eq!(
+            render("pub struct Day{{day_padded}}; // DAY {{ day }}", &values),
+            Ok("pub struct Day07; // DAY 7".into())
+        );
+        assert_eq!(render("{{title}}", &values), Ok("{{day}}".into()));
+        assert_eq!(
+            render("{{year}}", &values),
+            Err(TemplateError::UnknownPlaceholder("year".into()))
+        );
+        assert_eq!(render("{{day", &values), Err(TemplateError::Unclosed));
+    }
+
+    #[test]
+    fn test_unsolved() {
+        assert_eq!(unsolved("Option<u32>"), Some(UNSOLVED_OPTION));
+        assert_eq!(unsolved("Result<u64, String>"), Some(UNSOLVED_RESULT));
+        assert_eq!(unsolved("u32"), None);
+    }
+}